@@ -17,6 +17,9 @@ impl Compiler {
         let mut file_percentage: f64;
         let mut file_index: usize = 0;
 
+        // Every file is a module named after it, parsed before any of them is analyzed
+        let mut modules: Vec<(String, rudin::parser::statements::Statement)> = Vec::new();
+
         for file in self.state.input_files.iter() {
             file_index += 1;
 
@@ -25,7 +28,7 @@ impl Compiler {
                 Err(err) => {
                     self.output.push(rudin::handling::Message::new(
                         rudin::handling::MessageKind::Error,
-                        format!("Error reading file '{}': {}", file, err.to_string()),
+                        format!("Error reading file '{}': {}", file, err),
                         None,
                     ));
                     continue;
@@ -39,26 +42,108 @@ impl Compiler {
             let mut lexer: rudin::lexer::Lexer = rudin::lexer::Lexer::new(content);
             lexer.lex();
 
-            if lexer.output.len() != 0 {
+            if !lexer.output.is_empty() {
                 self.collect_messages(lexer.output);
-                break;
+                return;
             }
 
             let mut parser: rudin::parser::Parser = rudin::parser::Parser::new(lexer.tokens);
             parser.parse();
 
-            if parser.output.len() != 0 {
+            if !parser.output.is_empty() {
                 self.collect_messages(parser.output);
-                break;
+                return;
             }
 
             dbg!(parser.ast.clone());
 
-            let mut evaluator: rudin::evaluator::Evaluator =
-                rudin::evaluator::Evaluator::new(parser.ast.clone());
+            let module: String = std::path::Path::new(file)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
 
-            evaluator.evaluate();
+            modules.push((module, parser.ast));
         }
+
+        // The analyzed modules, with the types the analyzer inferred
+        let mut analyzed: Vec<(String, rudin::parser::statements::Statement)> = Vec::new();
+
+        for (module, ast) in modules.iter() {
+            let mut analyzer: rudin::analyzer::Analyzer =
+                rudin::analyzer::Analyzer::new(ast.clone());
+
+            for (other, other_ast) in modules.iter() {
+                if other != module {
+                    analyzer.add_module(other.clone(), other_ast.clone());
+                }
+            }
+
+            analyzer.analyze();
+
+            if !analyzer.output.is_empty() {
+                self.collect_messages(analyzer.output);
+                return;
+            }
+
+            analyzed.push((module.clone(), analyzer.ast));
+        }
+
+        let release: bool = self
+            .state
+            .flags
+            .iter()
+            .any(|flag| matches!(flag, state::CompilerFlags::Release));
+
+        let output: Vec<rudin::handling::Message> = Self::evaluate(&analyzed, release);
+        self.collect_messages(output);
+    }
+
+    /// Runs the analyzed modules on a thread with a stack deep enough for the evaluator.
+    fn evaluate(
+        analyzed: &[(String, rudin::parser::statements::Statement)],
+        release: bool,
+    ) -> Vec<rudin::handling::Message> {
+        let evaluation = || {
+            for (module, ast) in analyzed.iter() {
+                let mut evaluator: rudin::evaluator::Evaluator =
+                    rudin::evaluator::Evaluator::new(ast.clone());
+                evaluator.set_release(release);
+
+                for (other, other_ast) in analyzed.iter() {
+                    if other != module {
+                        evaluator.add_module(other.clone(), other_ast.clone());
+                    }
+                }
+
+                evaluator.evaluate();
+
+                if !evaluator.output.is_empty() {
+                    return evaluator.output;
+                }
+            }
+
+            Vec::new()
+        };
+
+        std::thread::scope(|scope| {
+            match std::thread::Builder::new()
+                .stack_size(rudin::evaluator::STACK_SIZE)
+                .spawn_scoped(scope, evaluation)
+            {
+                Ok(thread) => thread.join().unwrap_or_else(|_| {
+                    vec![rudin::handling::Message::new(
+                        rudin::handling::MessageKind::Error,
+                        "The evaluation panicked".to_string(),
+                        None,
+                    )]
+                }),
+                Err(err) => vec![rudin::handling::Message::new(
+                    rudin::handling::MessageKind::Error,
+                    format!("Error starting the evaluation: {}", err),
+                    None,
+                )],
+            }
+        })
     }
 
     fn collect_messages(&mut self, input: Vec<rudin::handling::Message>) {
@@ -12,7 +12,7 @@ fn main() {
 
     // If we have errors
     // TODO: We have to handle this better
-    if state.output.len() != 0 {
+    if !state.output.is_empty() {
         dbg!(state.output);
         std::process::exit(1);
     }
@@ -32,7 +32,7 @@ fn main() {
     let mut compiler: compiler::Compiler = compiler::Compiler::new(state);
     compiler.compile();
 
-    if compiler.output.len() != 0 {
+    if !compiler.output.is_empty() {
         dbg!(compiler.output);
         std::process::exit(1);
     }
@@ -1,8 +1,8 @@
-use rudin;
-
 #[derive(Debug)]
 pub enum CompilerFlags {
     CompilerDebug,
+    /// Builds the program in release mode, integer overflows wrap instead of stopping it
+    Release,
 }
 
 #[derive(Debug)]
@@ -74,15 +74,17 @@ impl State {
         match flag.as_str() {
             "-cdbg" => {
                 self.flags.push(CompilerFlags::CompilerDebug);
-                return None;
+                None
             }
-            _ => {
-                return Some(rudin::handling::Message::new(
-                    rudin::handling::MessageKind::Error,
-                    format!("Unknown flag: {}", flag),
-                    None,
-                ));
+            "-r" | "--release" => {
+                self.flags.push(CompilerFlags::Release);
+                None
             }
+            _ => Some(rudin::handling::Message::new(
+                rudin::handling::MessageKind::Error,
+                format!("Unknown flag: {}", flag),
+                None,
+            )),
         }
     }
 }
@@ -0,0 +1,169 @@
+//! Runs the programs of tests/errors, each one must stop with its diagnostic unless the flags it's
+//! built with allow it.
+
+/// Compiles and runs a program of tests/errors with the given flags.
+fn rudinc(program: &str, flags: &[&str]) -> std::process::Output {
+    let path: String = format!(
+        "{}/../../tests/errors/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        program
+    );
+
+    std::process::Command::new(env!("CARGO_BIN_EXE_rudinc"))
+        .args(flags)
+        .arg(path)
+        .output()
+        .expect("rudinc should start")
+}
+
+/// Checks that a program fails with a message containing the expected text.
+fn expect_diagnostic(program: &str, flags: &[&str], expected: &str) {
+    let output: std::process::Output = rudinc(program, flags);
+    let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+
+    assert!(!output.status.success(), "{} should fail", program);
+    assert!(
+        stderr.contains(expected),
+        "{} should report {:?}, it reported:\n{}",
+        program,
+        expected,
+        stderr
+    );
+}
+
+/// Checks that a program fails with a message containing the expected text, reported at a line
+/// and a column of the program, both counted from 0.
+fn expect_diagnostic_at(program: &str, flags: &[&str], expected: &str, position: (usize, usize)) {
+    expect_diagnostic(program, flags, expected);
+
+    let output: std::process::Output = rudinc(program, flags);
+    let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // The position follows the message in the printed output
+    let reported: Vec<usize> = stderr
+        .lines()
+        .skip_while(|line| !(line.contains("message:") && line.contains(expected)))
+        .filter_map(|line| {
+            let line: &str = line.trim();
+            line.strip_prefix("line: ")
+                .or_else(|| line.strip_prefix("column: "))
+                .and_then(|number| number.trim_end_matches(',').parse::<usize>().ok())
+        })
+        .take(2)
+        .collect();
+
+    assert_eq!(
+        reported,
+        vec![position.0, position.1],
+        "{} should report {:?} at {:?}, it reported:\n{}",
+        program,
+        expected,
+        position,
+        stderr
+    );
+}
+
+/// Checks that a program fails with a single message, containing the expected text.
+fn expect_single_diagnostic(program: &str, flags: &[&str], expected: &str) {
+    expect_diagnostic(program, flags, expected);
+
+    let output: std::process::Output = rudinc(program, flags);
+    let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+
+    assert_eq!(
+        stderr.matches("message:").count(),
+        1,
+        "{} should report a single message, it reported:\n{}",
+        program,
+        stderr
+    );
+}
+
+/// Checks that a program runs to its end and prints the expected lines.
+fn expect_output(program: &str, flags: &[&str], expected: &str) {
+    let output: std::process::Output = rudinc(program, flags);
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(output.status.success(), "{} should run", program);
+    assert!(
+        stdout.lines().any(|line| line == expected),
+        "{} should print {:?}, it printed:\n{}",
+        program,
+        expected,
+        stdout
+    );
+}
+
+#[test]
+fn call_depth() {
+    expect_diagnostic(
+        "call_depth.rudin",
+        &[],
+        r#"\"count\" -> Maximum call depth exceeded"#,
+    );
+}
+
+#[test]
+fn compound_assignment() {
+    expect_diagnostic(
+        "compound_assignment.rudin",
+        &[],
+        r#"Expected int but found double, convert it with \"as int\""#,
+    );
+}
+
+#[test]
+fn try_runtime_failure() {
+    expect_diagnostic("try_runtime_failure.rudin", &[], "Division by zero");
+}
+
+#[test]
+fn integer_overflow() {
+    expect_diagnostic(
+        "integer_overflow.rudin",
+        &[],
+        "Overflow, the result doesn't fit in u8",
+    );
+}
+
+#[test]
+fn integer_overflow_release() {
+    expect_output("integer_overflow.rudin", &["--release"], "4");
+}
+
+#[test]
+fn unknown_tuple_type() {
+    expect_diagnostic(
+        "unknown_tuple_type.rudin",
+        &[],
+        r#"\"Nope\" -> Unknown type"#,
+    );
+}
+
+#[test]
+fn missing_return_value() {
+    expect_diagnostic(
+        "missing_return_value.rudin",
+        &[],
+        r#"\"return\" -> Expected a value of type int"#,
+    );
+}
+
+#[test]
+fn index_without_overload() {
+    expect_single_diagnostic(
+        "index_without_overload.rudin",
+        &[],
+        r#"Operator [] isn't defined for Point"#,
+    );
+}
+
+#[test]
+fn argument_position() {
+    expect_diagnostic_at(
+        "argument_position.rudin",
+        &[],
+        "Expected int but found str",
+        (6, 8),
+    );
+}
@@ -0,0 +1,378 @@
+//! Runs the sample programs of tests and compares what they print with the expected lines.
+
+/// Compiles and runs sample programs together, checks that they run to their end and print
+/// exactly the expected lines.
+fn expect_lines(programs: &[&str], expected: &[&str]) {
+    let paths: Vec<String> = programs
+        .iter()
+        .map(|program| format!("{}/../../tests/{}", env!("CARGO_MANIFEST_DIR"), program))
+        .collect();
+
+    let output: std::process::Output = std::process::Command::new(env!("CARGO_BIN_EXE_rudinc"))
+        .args(paths)
+        .output()
+        .expect("rudinc should start");
+
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(
+        output.status.success(),
+        "{:?} should run, it reported:\n{}",
+        programs,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The progress of the compilation comes first, a line for each file
+    let printed: Vec<&str> = stdout.lines().skip(programs.len()).collect();
+
+    assert_eq!(printed, expected, "{:?} printed:\n{}", programs, stdout);
+}
+
+#[test]
+fn argument_statements() {
+    expect_lines(
+        &["argument_statements.rudin"],
+        &[
+            "Hello, world!",
+            "Welcome, Ada!",
+            "Hello, Alan?",
+            "Hi, Grace.",
+            "9",
+            "12",
+            "9",
+            "8",
+            "Rect { width: 2, height: 4 }",
+            "Rect { width: 3, height: 6 }",
+            "heyhey",
+        ],
+    );
+}
+
+#[test]
+fn array_statements() {
+    expect_lines(
+        &["array_statements.rudin"],
+        &["16", "[[0, 0, 0], [0, 0, 7]]", "7", "[1.5, 2.5]"],
+    );
+}
+
+#[test]
+fn assignment_statements() {
+    expect_lines(
+        &["assignment_statements.rudin"],
+        &["32", "10", "2", "Rudin lang"],
+    );
+}
+
+#[test]
+fn bitwise_statements() {
+    expect_lines(
+        &["bitwise_statements.rudin"],
+        &[
+            "5", "false", "true", "true", "6", "2", "8", "8", "-4", "5", "16", "8",
+        ],
+    );
+}
+
+#[test]
+fn cast_statements() {
+    expect_lines(
+        &["cast_statements.rudin"],
+        &[
+            "2",
+            "2",
+            "3",
+            "65",
+            "C",
+            "-2",
+            "2",
+            "false",
+            "2 apples",
+            "2.3333333333333335",
+        ],
+    );
+}
+
+#[test]
+fn class_definition() {
+    expect_lines(&["class_definition.rudin"], &[]);
+}
+
+#[test]
+fn closure_statements() {
+    expect_lines(
+        &["closure_statements.rudin"],
+        &[
+            "3",
+            "15",
+            "3",
+            "42",
+            "-3",
+            "81",
+            "ok clicked",
+            "func(int) -> int",
+        ],
+    );
+}
+
+#[test]
+fn const_statements() {
+    expect_lines(&["const_statements.rudin"], &[]);
+}
+
+#[test]
+fn defer_statements() {
+    expect_lines(
+        &["defer_statements.rudin"],
+        &[
+            "0",
+            "step done",
+            "step done",
+            "2",
+            "step done",
+            "work: closing",
+            "work: cleanup",
+            "6",
+            "report: done",
+            "5",
+            "report: done",
+            "3",
+            "failing: cleanup",
+            "failed",
+        ],
+    );
+}
+
+#[test]
+fn error_statements() {
+    expect_lines(
+        &["error_statements.rudin"],
+        &[
+            "42",
+            "caught: not a digit: x",
+            "caught without a name",
+            "too large",
+        ],
+    );
+}
+
+#[test]
+fn expression_statements() {
+    expect_lines(
+        &["expression_statements.rudin"],
+        &[
+            "20",
+            "14",
+            "10",
+            "20",
+            "true",
+            "1",
+            "2",
+            "1",
+            "-9223372036854775808",
+            "true",
+            "8",
+            "10",
+            "3",
+            "4",
+            "-2",
+            "0",
+            "1.5",
+        ],
+    );
+}
+
+#[test]
+fn for_in_statements() {
+    expect_lines(
+        &["for_in_statements.rudin"],
+        &[
+            "0", "1", "2", "16", "15", "a", "b", "c", "3", "2", "1", "ada", "alan", "5..5",
+        ],
+    );
+}
+
+#[test]
+fn function_statements() {
+    expect_lines(&["function_statements.rudin"], &[]);
+}
+
+#[test]
+fn generic_statements() {
+    expect_lines(
+        &["generic_statements.rudin"],
+        &[
+            "2.5",
+            "banana",
+            "5",
+            "Point { x: 7, y: 0 }",
+            "42",
+            "Pair<int, str> { first: 42, second: answer }",
+            "c",
+        ],
+    );
+}
+
+#[test]
+fn hello_world() {
+    expect_lines(&["hello_world.rudin"], &["Hello, World!"]);
+}
+
+#[test]
+fn identifiers_statements() {
+    expect_lines(&["identifiers_statements.rudin"], &["Hello, World!"]);
+}
+
+#[test]
+fn if_expression_statements() {
+    expect_lines(
+        &["if_expression_statements.rudin"],
+        &[
+            "10",
+            "negative zero positive",
+            "clamping 42",
+            "3 10",
+            "12",
+            "1",
+            "8 -1",
+        ],
+    );
+}
+
+#[test]
+fn if_statements() {
+    expect_lines(&["if_statements.rudin"], &["0", "1", "2", "3", "4"]);
+}
+
+#[test]
+fn inference_statements() {
+    expect_lines(
+        &["inference_statements.rudin"],
+        &[
+            "12.56",
+            "[1, 2, 3, 4]",
+            "[1, 2.5]",
+            "5",
+            "4",
+            "true",
+            "Hello, inference!",
+        ],
+    );
+}
+
+#[test]
+fn labeled_loop_statements() {
+    expect_lines(
+        &["labeled_loop_statements.rudin"],
+        &["(1, 2)", "(-1, -1)", "3", "8", "(4, 6)", "5"],
+    );
+}
+
+#[test]
+fn loop_statements() {
+    expect_lines(&["loop_statements.rudin"], &["for", "loop", "3"]);
+}
+
+#[test]
+fn member_statements() {
+    expect_lines(&["member_statements.rudin"], &["Hello, World!", "5", "8"]);
+}
+
+#[test]
+fn modules() {
+    expect_lines(
+        &["modules/geometry.rudin", "modules/shapes.rudin"],
+        &["12", "8", "2"],
+    );
+}
+
+#[test]
+fn nullable_statements() {
+    expect_lines(
+        &["nullable_statements.rudin"],
+        &[
+            "null",
+            "10",
+            "1",
+            "-1",
+            "2",
+            "null",
+            "0",
+            "2",
+            "0",
+            "nobody",
+            "name: Ada",
+            "3",
+        ],
+    );
+}
+
+#[test]
+fn operator_overloading_statements() {
+    expect_lines(
+        &["operator_overloading_statements.rudin"],
+        &["4", "6", "6", "4", "true", "true", "true", "6"],
+    );
+}
+
+#[test]
+fn sized_integer_statements() {
+    expect_lines(
+        &["sized_integer_statements.rudin"],
+        &[
+            "127",
+            "51",
+            "0",
+            "127",
+            "32768",
+            "0",
+            "15",
+            "18446744073709551615",
+            "44",
+            "4294967295",
+            "127",
+            "ÿ",
+            "21",
+            "3",
+            "20",
+            "true",
+            "3",
+            "18446744073709551614",
+            "18446744073709551615",
+        ],
+    );
+}
+
+#[test]
+fn static_member_statements() {
+    expect_lines(
+        &["static_member_statements.rudin"],
+        &["3", "0", "2", "2", "10", "4"],
+    );
+}
+
+#[test]
+fn tuple_statements() {
+    expect_lines(
+        &["tuple_statements.rudin"],
+        &[
+            "(1, one)", "1", "one", "3", "2", "8", "2", "(1.5, 2)", "true", "3", "4",
+        ],
+    );
+}
+
+#[test]
+fn type_alias_statements() {
+    expect_lines(
+        &["type_alias_statements.rudin"],
+        &["16.5", "ana", "42", "3", "8", "true", "1.0166666666666666"],
+    );
+}
+
+#[test]
+fn variadic_statements() {
+    expect_lines(
+        &["variadic_statements.rudin"],
+        &["0", "6", "15", "30", "a, b, c", "", "2.5", "2.5"],
+    );
+}
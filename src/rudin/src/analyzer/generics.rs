@@ -0,0 +1,618 @@
+use std::collections::HashMap;
+
+use crate::*;
+
+/// The most instances the generic declarations of a module can produce, a recursive
+/// instantiation stops here.
+const MAX_INSTANCES: usize = 256;
+
+/// Checks if a declaration is generic, only its instances are kept after the analysis.
+pub fn is_template(statement: &parser::statements::Statement) -> bool {
+    matches!(
+        statement,
+        parser::statements::Statement::FunctionDeclaration { generics, .. }
+        | parser::statements::Statement::TypeDeclaration { generics, .. }
+            if !generics.is_empty()
+    )
+}
+
+/// The name of an instance of a generic declaration -> max<int>, Box<str>
+pub fn instance_name(name: &str, arguments: &[internals::types::Types]) -> String {
+    internals::types::Types::Generic(name.to_string(), arguments.to_vec()).to_string()
+}
+
+/// Replaces the type parameters of a type by their arguments.
+pub fn substitute_type(
+    r#type: &internals::types::Types,
+    substitutions: &HashMap<String, internals::types::Types>,
+) -> internals::types::Types {
+    let substitute_all = |types: &Vec<internals::types::Types>| -> Vec<internals::types::Types> {
+        types
+            .iter()
+            .map(|r#type| substitute_type(r#type, substitutions))
+            .collect()
+    };
+
+    match r#type {
+        internals::types::Types::Custom(name) => match substitutions.get(name) {
+            Some(argument) => argument.clone(),
+            None => r#type.clone(),
+        },
+        internals::types::Types::Array(element, size) => {
+            internals::types::Types::Array(Box::new(substitute_type(element, substitutions)), *size)
+        }
+        internals::types::Types::Slice(element) => {
+            internals::types::Types::Slice(Box::new(substitute_type(element, substitutions)))
+        }
+        internals::types::Types::Optional(r#type) => {
+            internals::types::Types::Optional(Box::new(substitute_type(r#type, substitutions)))
+        }
+        internals::types::Types::Result(r#type) => {
+            internals::types::Types::Result(Box::new(substitute_type(r#type, substitutions)))
+        }
+        internals::types::Types::Tuple(types) => {
+            internals::types::Types::Tuple(substitute_all(types))
+        }
+        internals::types::Types::Vector(types) => {
+            internals::types::Types::Vector(substitute_all(types))
+        }
+        internals::types::Types::Generic(name, arguments) => {
+            internals::types::Types::Generic(name.clone(), substitute_all(arguments))
+        }
+        internals::types::Types::Function(params, r#type) => internals::types::Types::Function(
+            substitute_all(params),
+            Box::new(substitute_type(r#type, substitutions)),
+        ),
+        _ => r#type.clone(),
+    }
+}
+
+/// Replaces the type parameters inside a declaration and its body.
+pub fn substitute_statement(
+    statement: &mut parser::statements::Statement,
+    substitutions: &HashMap<String, internals::types::Types>,
+) {
+    match statement {
+        parser::statements::Statement::VariableDeclaration { r#type, .. }
+        | parser::statements::Statement::ConstantDeclaration { r#type, .. }
+        | parser::statements::Statement::Destructure { r#type, .. } => {
+            *r#type = substitute_type(r#type, substitutions)
+        }
+        parser::statements::Statement::FunctionDeclaration {
+            params,
+            r#type,
+            body,
+            ..
+        } => {
+            for param in params.iter_mut().flatten() {
+                param.r#type = substitute_type(&param.r#type, substitutions);
+            }
+
+            *r#type = substitute_type(r#type, substitutions);
+            substitute_block(body.as_deref_mut(), substitutions);
+        }
+        parser::statements::Statement::TypeDeclaration {
+            fields, methods, ..
+        } => {
+            for field in fields.iter_mut() {
+                field.r#type = substitute_type(&field.r#type, substitutions);
+            }
+
+            for method in methods.iter_mut() {
+                substitute_statement(method, substitutions);
+            }
+        }
+        parser::statements::Statement::If {
+            body, alternate, ..
+        }
+        | parser::statements::Statement::ElseIf {
+            body, alternate, ..
+        } => {
+            substitute_block(body.as_deref_mut(), substitutions);
+            substitute_block(alternate.as_deref_mut(), substitutions);
+        }
+        parser::statements::Statement::Else { body, .. }
+        | parser::statements::Statement::While { body, .. }
+        | parser::statements::Statement::Loop { body, .. }
+        | parser::statements::Statement::ForIn { body, .. }
+        | parser::statements::Statement::Defer { body, .. } => {
+            substitute_block(body.as_deref_mut(), substitutions)
+        }
+        parser::statements::Statement::Try { body, catch, .. } => {
+            substitute_block(body.as_deref_mut(), substitutions);
+            substitute_block(catch.as_deref_mut(), substitutions);
+        }
+        parser::statements::Statement::For { variable, body, .. } => {
+            if let Some(variable) = variable {
+                substitute_statement(variable, substitutions);
+            }
+
+            substitute_block(body.as_deref_mut(), substitutions);
+        }
+        _ => {}
+    }
+}
+
+fn substitute_block(
+    block: Option<&mut Vec<parser::statements::Statement>>,
+    substitutions: &HashMap<String, internals::types::Types>,
+) {
+    for statement in block.into_iter().flat_map(|block| block.iter_mut()) {
+        substitute_statement(statement, substitutions);
+    }
+}
+
+impl analyzer::Analyzer {
+    /// Checks that the bounds of the type parameters of a declaration are known traits.
+    pub(super) fn check_generic_params(
+        &mut self,
+        generics: &[parser::statements::GenericParam],
+        start: &internals::Position,
+    ) {
+        for bound in generics.iter().flat_map(|param| param.bounds.iter()) {
+            if !internals::types::BUILTIN_TRAITS.contains(&bound.as_str()) {
+                self.error(format!("\"{}\" -> Unknown trait", bound), start);
+            }
+        }
+    }
+
+    /// Checks that every type argument implements the bounds of its parameter.
+    fn check_bounds(
+        &mut self,
+        name: &str,
+        generics: &[parser::statements::GenericParam],
+        arguments: &[internals::types::Types],
+        start: &internals::Position,
+    ) -> bool {
+        let mut satisfied: bool = true;
+
+        for (param, argument) in generics.iter().zip(arguments) {
+            for bound in param.bounds.iter() {
+                if internals::types::BUILTIN_TRAITS.contains(&bound.as_str())
+                    && !argument.implements(bound)
+                {
+                    self.error(
+                        format!(
+                            "\"{}\" -> {} doesn't implement {} required by {}",
+                            name, argument, bound, param.name
+                        ),
+                        start,
+                    );
+                    satisfied = false;
+                }
+            }
+        }
+
+        satisfied
+    }
+
+    /// Binds the type parameters found in a declared type to the matching parts of the type
+    /// of a value, returns the parameter bound to two different types.
+    fn unify(
+        &self,
+        generics: &[parser::statements::GenericParam],
+        parameter: &internals::types::Types,
+        argument: &internals::types::Types,
+        substitutions: &mut HashMap<String, internals::types::Types>,
+    ) -> Result<(), String> {
+        match (parameter, argument) {
+            (internals::types::Types::Custom(name), _)
+                if generics.iter().any(|param| param.name == *name) =>
+            {
+                let bound: internals::types::Types = match substitutions.get(name) {
+                    None => argument.clone(),
+                    Some(bound) if bound == argument => bound.clone(),
+                    // Numbers meet at the largest of them -> max(1, 2.5) is max<double>
+                    Some(bound) => match bound.common_numeric(argument) {
+                        Some(common) => common,
+                        None => {
+                            return Err(name.clone());
+                        }
+                    },
+                };
+
+                substitutions.insert(name.clone(), bound);
+                Ok(())
+            }
+            (
+                internals::types::Types::Array(parameter, _)
+                | internals::types::Types::Slice(parameter),
+                internals::types::Types::Array(argument, _)
+                | internals::types::Types::Slice(argument),
+            ) => self.unify(generics, parameter, argument, substitutions),
+            // Null says nothing about the type -> T? and null
+            (internals::types::Types::Optional(_), internals::types::Types::Null) => Ok(()),
+            (
+                internals::types::Types::Optional(parameter),
+                internals::types::Types::Optional(argument),
+            ) => self.unify(generics, parameter, argument, substitutions),
+            (internals::types::Types::Optional(parameter), argument) => {
+                self.unify(generics, parameter, argument, substitutions)
+            }
+            (
+                internals::types::Types::Result(parameter),
+                internals::types::Types::Result(argument),
+            ) => self.unify(generics, parameter, argument, substitutions),
+            (
+                internals::types::Types::Function(parameters, parameter),
+                internals::types::Types::Function(arguments, argument),
+            ) => {
+                for (parameter, argument) in parameters.iter().zip(arguments) {
+                    self.unify(generics, parameter, argument, substitutions)?;
+                }
+
+                self.unify(generics, parameter, argument, substitutions)
+            }
+            // An instance of the same generic type -> Box<T> and Box<int>
+            (
+                internals::types::Types::Generic(name, parameters),
+                internals::types::Types::Custom(instance),
+            ) => match self.type_instances.get(instance) {
+                Some((template, arguments)) if template == name => {
+                    for (parameter, argument) in parameters.iter().zip(arguments) {
+                        self.unify(generics, parameter, argument, substitutions)?;
+                    }
+
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Infers the type arguments of a generic declaration from the declared types of its
+    /// values and the expressions given to them.
+    fn infer_arguments(
+        &self,
+        generics: &[parser::statements::GenericParam],
+        parameters: &[internals::types::Types],
+        values: &[&parser::statements::Expression],
+    ) -> Result<Vec<internals::types::Types>, Option<String>> {
+        let mut substitutions: HashMap<String, internals::types::Types> = HashMap::new();
+
+        for (parameter, value) in parameters.iter().zip(values) {
+            if let Some(argument) = self.expression_type(value)
+                && argument.is_known()
+            {
+                self.unify(generics, parameter, &argument, &mut substitutions)
+                    .map_err(Some)?;
+            }
+        }
+
+        generics
+            .iter()
+            .map(|param| substitutions.get(&param.name).cloned().ok_or(None))
+            .collect()
+    }
+
+    /// Returns the signature of a generic function -> (type parameters, parameters, return type)
+    fn function_template(
+        &self,
+        name: &str,
+    ) -> Option<(
+        Vec<parser::statements::GenericParam>,
+        Vec<internals::types::Types>,
+        internals::types::Types,
+    )> {
+        match self.function_templates.get(name)? {
+            parser::statements::Statement::FunctionDeclaration {
+                generics,
+                params,
+                r#type,
+                ..
+            } => Some((
+                generics.clone(),
+                params
+                    .iter()
+                    .flatten()
+                    .map(|param| param.r#type.clone())
+                    .collect(),
+                r#type.clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Infers the type arguments of a generic struct or class from a literal of it.
+    pub(super) fn struct_literal_arguments(
+        &self,
+        name: &str,
+        fields: &[(String, parser::statements::Expression)],
+    ) -> Option<Vec<internals::types::Types>> {
+        let (generics, declared) = match self.type_templates.get(name)? {
+            parser::statements::Statement::TypeDeclaration {
+                generics, fields, ..
+            } => (generics, fields),
+            _ => {
+                return None;
+            }
+        };
+
+        let mut parameters: Vec<internals::types::Types> = Vec::new();
+        let mut values: Vec<&parser::statements::Expression> = Vec::new();
+
+        for (field, value) in fields {
+            if let Some(declared) = declared.iter().find(|declared| declared.name == *field) {
+                parameters.push(declared.r#type.clone());
+                values.push(value);
+            }
+        }
+
+        self.infer_arguments(generics, &parameters, &values).ok()
+    }
+
+    /// The type a call to a generic function results in, without instantiating it.
+    pub(super) fn generic_call_type(
+        &self,
+        name: &str,
+        arguments: Option<&Vec<parser::statements::Expression>>,
+    ) -> Option<internals::types::Types> {
+        let (generics, parameters, r#type) = self.function_template(name)?;
+        let values: Vec<&parser::statements::Expression> =
+            arguments.into_iter().flatten().collect();
+
+        let arguments: Vec<internals::types::Types> =
+            self.infer_arguments(&generics, &parameters, &values).ok()?;
+
+        let substitutions: HashMap<String, internals::types::Types> = generics
+            .iter()
+            .map(|param| param.name.clone())
+            .zip(arguments)
+            .collect();
+
+        Some(instance_type(&substitute_type(&r#type, &substitutions)))
+    }
+
+    /// Replaces a call to a generic function by a call to its instance for the arguments.
+    pub(super) fn analyze_generic_call(
+        &mut self,
+        callee: &mut parser::statements::Expression,
+        arguments: Option<&Vec<parser::statements::Expression>>,
+        start: &internals::Position,
+    ) {
+        // The instances belong to the module being analyzed
+        if let parser::statements::Expression::Member { object, member } = callee
+            && let parser::statements::Expression::Identifier(module) = object.as_ref()
+            && self.imports.contains(module)
+            && self.lookup(module).is_none()
+            && let Some(declaration) = self.module_declaration(module, member)
+            && is_template(declaration)
+        {
+            self.error(
+                format!(
+                    "\"{}.{}\" -> Generic functions of another module can't be instantiated",
+                    module, member
+                ),
+                start,
+            );
+            return;
+        }
+
+        let name: &mut String = match callee {
+            parser::statements::Expression::Identifier(name)
+                if self.function_templates.contains_key(name.as_str())
+                    && self.lookup(name).is_none() =>
+            {
+                name
+            }
+            _ => {
+                return;
+            }
+        };
+
+        let (generics, parameters, _) = match self.function_template(name) {
+            Some(template) => template,
+            None => {
+                return;
+            }
+        };
+
+        let values: Vec<&parser::statements::Expression> =
+            arguments.into_iter().flatten().collect();
+
+        if values.len() != parameters.len() {
+            self.error(
+                format!(
+                    "\"{}\" -> Expected {} arguments but found {}",
+                    name,
+                    parameters.len(),
+                    values.len()
+                ),
+                start,
+            );
+            return;
+        }
+
+        match self.infer_arguments(&generics, &parameters, &values) {
+            Ok(arguments) => {
+                if let Some(instance) = self.instantiate_function(&name.clone(), arguments, start) {
+                    *name = instance;
+                }
+            }
+            Err(Some(param)) => self.error(
+                format!(
+                    "\"{}\" -> Conflicting types for the type parameter {}",
+                    name, param
+                ),
+                start,
+            ),
+            Err(None) => self.error(
+                format!("\"{}\" -> Can't infer the type arguments", name),
+                start,
+            ),
+        }
+    }
+
+    /// Creates the instance of a generic function for the type arguments, once.
+    pub(super) fn instantiate_function(
+        &mut self,
+        name: &str,
+        arguments: Vec<internals::types::Types>,
+        start: &internals::Position,
+    ) -> Option<String> {
+        let mut declaration: parser::statements::Statement =
+            self.function_templates.get(name)?.clone();
+
+        let instance: String = instance_name(name, &arguments);
+
+        if self.functions.contains_key(&instance) {
+            return Some(instance);
+        }
+
+        let substitutions: HashMap<String, internals::types::Types> =
+            self.prepare_instance(name, &instance, &mut declaration, arguments, start)?;
+        substitute_statement(&mut declaration, &substitutions);
+
+        self.resolve_signature(&mut declaration);
+        self.declare_statement(&declaration);
+        self.analyze_instance(&instance, &mut declaration);
+        self.instances.push(declaration);
+
+        Some(instance)
+    }
+
+    /// Creates the instance of a generic struct or class for the type arguments, once.
+    pub(super) fn instantiate_type(
+        &mut self,
+        name: &str,
+        arguments: Vec<internals::types::Types>,
+        start: &internals::Position,
+    ) -> Option<String> {
+        let mut declaration: parser::statements::Statement = self.type_templates.get(name)?.clone();
+
+        let instance: String = instance_name(name, &arguments);
+
+        if self.types.contains_key(&instance) {
+            return Some(instance);
+        }
+
+        self.type_instances
+            .insert(instance.clone(), (name.to_string(), arguments.clone()));
+
+        let substitutions: HashMap<String, internals::types::Types> =
+            self.prepare_instance(name, &instance, &mut declaration, arguments, start)?;
+        substitute_statement(&mut declaration, &substitutions);
+
+        // Known before its fields are resolved, they can refer to the instance itself
+        self.types.insert(instance.clone(), declaration.clone());
+        self.resolve_signature(&mut declaration);
+        self.analyze_instance(&instance, &mut declaration);
+        self.instances.push(declaration);
+
+        Some(instance)
+    }
+
+    /// Checks the type arguments of an instance and renames its declaration, returns the
+    /// type each parameter is replaced by.
+    fn prepare_instance(
+        &mut self,
+        name: &str,
+        instance: &str,
+        declaration: &mut parser::statements::Statement,
+        arguments: Vec<internals::types::Types>,
+        start: &internals::Position,
+    ) -> Option<HashMap<String, internals::types::Types>> {
+        let generics: Vec<parser::statements::GenericParam> = match declaration {
+            parser::statements::Statement::FunctionDeclaration { name, generics, .. }
+            | parser::statements::Statement::TypeDeclaration { name, generics, .. } => {
+                *name = instance.to_string();
+                std::mem::take(generics)
+            }
+            _ => {
+                return None;
+            }
+        };
+
+        // The static members of a generic type were reported on its declaration
+        if let parser::statements::Statement::TypeDeclaration { statics, .. } = declaration {
+            statics.clear();
+        }
+
+        if generics.len() != arguments.len() {
+            self.error(
+                format!(
+                    "\"{}\" -> Expected {} type arguments but found {}",
+                    name,
+                    generics.len(),
+                    arguments.len()
+                ),
+                start,
+            );
+            return None;
+        }
+
+        if !self.check_bounds(name, &generics, &arguments, start) {
+            return None;
+        }
+
+        if self.instances.len() >= MAX_INSTANCES {
+            self.error(
+                format!(
+                    "\"{}\" -> Too many instances of a generic declaration",
+                    name
+                ),
+                start,
+            );
+            return None;
+        }
+
+        Some(
+            generics
+                .into_iter()
+                .map(|param| param.name)
+                .zip(arguments)
+                .collect(),
+        )
+    }
+
+    /// Analyzes an instance as if it was declared at the top level of the module, its
+    /// declarations get the types inferred for this instance.
+    fn analyze_instance(
+        &mut self,
+        instance: &str,
+        declaration: &mut parser::statements::Statement,
+    ) {
+        let scopes: Vec<HashMap<String, analyzer::Symbol>> = self.scopes.split_off(1);
+        let self_type: Option<String> = self.self_type.take();
+        let loops: Vec<analyzer::Loop> = std::mem::take(&mut self.loops);
+        let outer: Option<String> = self.instance.replace(instance.to_string());
+
+        self.analyze_statement(declaration);
+        self.annotate_statement(declaration);
+
+        self.instance = outer;
+        self.scopes.extend(scopes);
+        self.self_type = self_type;
+        self.loops = loops;
+    }
+}
+
+/// Names the generic types inside a type after their instances -> Box<int>[] is a slice of
+/// the "Box<int>" instance
+fn instance_type(r#type: &internals::types::Types) -> internals::types::Types {
+    match r#type {
+        internals::types::Types::Generic(name, arguments) => {
+            let arguments: Vec<internals::types::Types> =
+                arguments.iter().map(instance_type).collect();
+            internals::types::Types::Custom(instance_name(name, &arguments))
+        }
+        internals::types::Types::Array(element, size) => {
+            internals::types::Types::Array(Box::new(instance_type(element)), *size)
+        }
+        internals::types::Types::Slice(element) => {
+            internals::types::Types::Slice(Box::new(instance_type(element)))
+        }
+        internals::types::Types::Optional(r#type) => {
+            internals::types::Types::Optional(Box::new(instance_type(r#type)))
+        }
+        internals::types::Types::Result(r#type) => {
+            internals::types::Types::Result(Box::new(instance_type(r#type)))
+        }
+        internals::types::Types::Function(params, r#type) => internals::types::Types::Function(
+            params.iter().map(instance_type).collect(),
+            Box::new(instance_type(r#type)),
+        ),
+        _ => r#type.clone(),
+    }
+}
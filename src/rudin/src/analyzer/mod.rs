@@ -0,0 +1,3680 @@
+pub mod generics;
+
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::*;
+
+/// What the analyzer knows about a declared name.
+#[derive(Debug, Clone)]
+struct Symbol {
+    r#type: internals::types::Types,
+    constant: bool,
+    // The folded value of an integer constant
+    value: Option<i64>,
+    // Where a variable waiting for its type to be inferred was declared
+    start: Option<internals::Position>,
+    // The optional type of a variable narrowed to its non-null type after a null check
+    declared: Option<internals::types::Types>,
+}
+
+/// The signature of a function that can be called.
+#[derive(Debug, Clone)]
+struct Function {
+    params: Vec<parser::statements::FuncParam>,
+    r#type: internals::types::Types,
+}
+
+/// A loop around the statements being analyzed.
+#[derive(Debug, Clone)]
+struct Loop {
+    label: Option<String>,
+    // Only a loop used as a value can be left with one
+    valued: bool,
+    // The type of the values it's left with, from its first break
+    r#type: Option<internals::types::Types>,
+}
+
+/// Checks the rules of the language that the parser can't see, before the evaluation.
+pub struct Analyzer {
+    // The analyzed program, its declarations get their inferred types
+    pub ast: parser::statements::Statement,
+    pub output: Vec<handling::Message>,
+    sources: HashMap<String, parser::statements::Statement>,
+    imports: Vec<String>,
+    scopes: Vec<HashMap<String, Symbol>>,
+    // The functions of the program, generic instances included
+    functions: HashMap<String, Function>,
+    // The structs and classes of the program, generic instances included
+    types: HashMap<String, parser::statements::Statement>,
+    // The type aliases of the program, distinct ones included
+    aliases: HashMap<String, parser::statements::Statement>,
+    // The aliases whose type is being resolved, to find the ones that refer to themselves
+    aliasing: Vec<String>,
+    // The generic declarations, only their instances reach the evaluation
+    function_templates: HashMap<String, parser::statements::Statement>,
+    type_templates: HashMap<String, parser::statements::Statement>,
+    // The generic type and the type arguments of each type instance -> Box<int>
+    type_instances: HashMap<String, (String, Vec<internals::types::Types>)>,
+    // The declarations specialised for each instantiation of a generic one
+    instances: Vec<parser::statements::Statement>,
+    // The type whose methods are being analyzed
+    self_type: Option<String>,
+    // The return type of the function being analyzed, if it's known
+    return_type: Option<internals::types::Types>,
+    // If the function being analyzed throws, its errors can be propagated
+    throws: bool,
+    // The try blocks around the statement being analyzed, their errors are caught
+    try_depth: usize,
+    // Set by "?" for the call it follows
+    handled: bool,
+    // A deferred body can't leave the function it runs in
+    deferred: bool,
+    // The types inferred for the declarations without annotation, by generic instance and
+    // position, the instances of a generic declaration share the positions of its body
+    inferred: Vec<(Option<String>, internals::Position, internals::types::Types)>,
+    // The generic instance being analyzed
+    instance: Option<String>,
+    // The loops around the statement being analyzed, the innermost last
+    loops: Vec<Loop>,
+}
+
+impl Analyzer {
+    pub fn new(ast: parser::statements::Statement) -> Self {
+        Self {
+            ast,
+            output: Vec::new(),
+            sources: HashMap::new(),
+            imports: Vec::new(),
+            scopes: vec![HashMap::new()],
+            functions: HashMap::new(),
+            types: HashMap::new(),
+            aliases: HashMap::new(),
+            aliasing: Vec::new(),
+            function_templates: HashMap::new(),
+            type_templates: HashMap::new(),
+            type_instances: HashMap::new(),
+            instances: Vec::new(),
+            self_type: None,
+            return_type: None,
+            throws: false,
+            try_depth: 0,
+            handled: false,
+            deferred: false,
+            inferred: Vec::new(),
+            instance: None,
+            loops: Vec::new(),
+        }
+    }
+
+    /// Makes a module available to the "use" statements of the program.
+    pub fn add_module(&mut self, name: String, parser_output: parser::statements::Statement) {
+        self.sources.insert(name, parser_output);
+    }
+
+    pub fn analyze(&mut self) {
+        let mut ast: parser::statements::Statement = std::mem::replace(
+            &mut self.ast,
+            parser::statements::Statement::Program {
+                start: internals::Position::new(0, 0),
+                body: Box::new(Vec::new()),
+            },
+        );
+
+        match &mut ast {
+            parser::statements::Statement::Program { body, .. } => {
+                // Types first, every other declaration can refer to them
+                for statement in body.iter() {
+                    self.declare_type(statement);
+                }
+
+                for statement in body.iter_mut() {
+                    self.resolve_signature(statement);
+                }
+
+                // Functions next, so the globals can be inferred from their calls
+                for statement in body.iter().filter(|statement| {
+                    matches!(
+                        statement,
+                        parser::statements::Statement::FunctionDeclaration { .. }
+                    )
+                }) {
+                    self.declare_statement(statement);
+                }
+
+                // Globals are visible inside every function, even the ones declared before them
+                for statement in body.iter() {
+                    self.declare_statement(statement);
+                }
+
+                for statement in body.iter_mut() {
+                    self.analyze_statement(statement);
+                }
+
+                self.check_inferred_scope(0);
+
+                // The generic declarations are replaced by their instances, declared first
+                body.retain(|statement| !generics::is_template(statement));
+                body.splice(0..0, std::mem::take(&mut self.instances));
+            }
+            _ => self.output.push(handling::Message::new(
+                handling::MessageKind::Error,
+                "Invalid AST".to_string(),
+                None,
+            )),
+        }
+
+        self.annotate_statement(&mut ast);
+        self.ast = ast;
+    }
+
+    /// Pushes a semantic error at the given position, once.
+    fn error(&mut self, message: String, position: &internals::Position) {
+        // The instances of a generic declaration repeat the errors of its body
+        if self
+            .output
+            .iter()
+            .any(|other| other.message == message && other.position.as_ref() == Some(position))
+        {
+            return;
+        }
+
+        self.output.push(handling::Message::new(
+            handling::MessageKind::CodeError,
+            message,
+            Some(position.clone()),
+        ));
+    }
+
+    /// Declares a name in the innermost scope.
+    fn declare(&mut self, name: &str, r#type: internals::types::Types, constant: bool) {
+        self.declare_symbol(
+            name,
+            Symbol {
+                r#type,
+                constant,
+                value: None,
+                start: None,
+                declared: None,
+            },
+        );
+    }
+
+    fn declare_symbol(&mut self, name: &str, symbol: Symbol) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), symbol);
+        }
+    }
+
+    /// Declares the name introduced by a declaration statement, if any.
+    fn declare_statement(&mut self, statement: &parser::statements::Statement) {
+        match statement {
+            parser::statements::Statement::VariableDeclaration {
+                start,
+                name,
+                r#type,
+                value,
+                ..
+            } => {
+                let r#type: internals::types::Types = match r#type {
+                    internals::types::Types::Unknown => match value {
+                        Some(value) => self.infer_type(name, value, start),
+                        // Inferred from the first assignment
+                        None => match self.inferred_type(start) {
+                            Some(r#type) => r#type,
+                            None => {
+                                self.declare_symbol(
+                                    name,
+                                    Symbol {
+                                        r#type: internals::types::Types::Unknown,
+                                        constant: false,
+                                        value: None,
+                                        start: Some(start.clone()),
+                                        declared: None,
+                                    },
+                                );
+                                return;
+                            }
+                        },
+                    },
+                    _ => r#type.clone(),
+                };
+
+                self.declare(name, r#type, false)
+            }
+            parser::statements::Statement::Destructure {
+                start,
+                names,
+                r#type,
+                value,
+                ..
+            } => {
+                let r#type: internals::types::Types = match r#type {
+                    internals::types::Types::Unknown => {
+                        self.infer_type(&format!("({})", names.join(", ")), value, start)
+                    }
+                    _ => r#type.clone(),
+                };
+
+                match r#type.unaliased() {
+                    internals::types::Types::Tuple(types) if types.len() == names.len() => {
+                        for (name, r#type) in names.iter().zip(types) {
+                            self.declare(name, r#type, false);
+                        }
+                    }
+                    internals::types::Types::Unknown => {
+                        for name in names.iter() {
+                            self.declare(name, internals::types::Types::Unknown, false);
+                        }
+                    }
+                    _ => {
+                        self.error(
+                            format!("Can't split {} into {} variables", r#type, names.len()),
+                            start,
+                        );
+
+                        for name in names.iter() {
+                            self.declare(name, internals::types::Types::Unknown, false);
+                        }
+                    }
+                }
+            }
+            parser::statements::Statement::ConstantDeclaration {
+                start,
+                name,
+                r#type,
+                value,
+                ..
+            } => {
+                let r#type: internals::types::Types = match r#type {
+                    internals::types::Types::Unknown => self.infer_type(name, value, start),
+                    _ => r#type.clone(),
+                };
+
+                let value: Option<i64> = match r#type {
+                    internals::types::Types::Int => self.fold_integer(value),
+                    _ => None,
+                };
+
+                self.declare_symbol(
+                    name,
+                    Symbol {
+                        r#type,
+                        constant: true,
+                        value,
+                        start: None,
+                        declared: None,
+                    },
+                );
+            }
+            parser::statements::Statement::FunctionDeclaration { name, generics, .. }
+                if !generics.is_empty() =>
+            {
+                self.function_templates
+                    .insert(name.clone(), statement.clone());
+            }
+            parser::statements::Statement::FunctionDeclaration {
+                name,
+                params,
+                r#type,
+                ..
+            } => {
+                self.functions.insert(
+                    name.clone(),
+                    Function {
+                        params: params.clone().unwrap_or_default(),
+                        r#type: r#type.clone(),
+                    },
+                );
+            }
+            parser::statements::Statement::Use { module, .. } => self.imports.push(module.clone()),
+            // The static members are globals named after their type -> Point::origin
+            parser::statements::Statement::TypeDeclaration {
+                generics, statics, ..
+            } if generics.is_empty() => {
+                let (functions, globals): (Vec<_>, Vec<_>) = statics.iter().partition(|member| {
+                    matches!(
+                        member,
+                        parser::statements::Statement::FunctionDeclaration { .. }
+                    )
+                });
+
+                for member in functions.into_iter().chain(globals) {
+                    self.declare_statement(member);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Registers a struct, a class or a type alias, generic ones are kept apart until they are
+    /// instantiated.
+    fn declare_type(&mut self, statement: &parser::statements::Statement) {
+        match statement {
+            parser::statements::Statement::TypeDeclaration { name, generics, .. } => {
+                if generics.is_empty() {
+                    self.types.insert(name.clone(), statement.clone());
+                } else {
+                    self.type_templates.insert(name.clone(), statement.clone());
+                }
+            }
+            parser::statements::Statement::TypeAlias { name, .. } => {
+                self.aliases.insert(name.clone(), statement.clone());
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolves the types written in the signature of a declaration.
+    fn resolve_signature(&mut self, statement: &mut parser::statements::Statement) {
+        match statement {
+            parser::statements::Statement::VariableDeclaration { start, r#type, .. }
+            | parser::statements::Statement::ConstantDeclaration { start, r#type, .. }
+            | parser::statements::Statement::Destructure { start, r#type, .. } => {
+                *r#type = self.resolve_type(r#type, start)
+            }
+            // The instances of a generic type would all share the same static members
+            parser::statements::Statement::TypeDeclaration {
+                start,
+                name,
+                generics,
+                statics,
+                ..
+            } if !generics.is_empty() && !statics.is_empty() => {
+                self.check_generic_params(generics, start);
+                self.error(
+                    format!("\"{}\" -> A generic type can't have static members", name),
+                    start,
+                );
+            }
+            // The signature of a generic declaration is resolved for each instance
+            parser::statements::Statement::FunctionDeclaration {
+                start, generics, ..
+            }
+            | parser::statements::Statement::TypeDeclaration {
+                start, generics, ..
+            } if !generics.is_empty() => self.check_generic_params(generics, start),
+            parser::statements::Statement::FunctionDeclaration {
+                start,
+                params,
+                r#type,
+                ..
+            } => {
+                for param in params.iter_mut().flatten() {
+                    param.r#type = self.resolve_type(&param.r#type, start);
+                }
+
+                *r#type = self.resolve_type(r#type, start);
+            }
+            parser::statements::Statement::TypeDeclaration {
+                start,
+                name,
+                fields,
+                methods,
+                statics,
+                ..
+            } => {
+                let self_type: Option<String> = self.self_type.replace(name.clone());
+
+                for field in fields.iter_mut() {
+                    field.r#type = self.resolve_type(&field.r#type, start);
+                }
+
+                for method in methods.iter_mut().chain(statics.iter_mut()) {
+                    self.resolve_signature(method);
+                }
+
+                self.self_type = self_type;
+            }
+            parser::statements::Statement::TypeAlias {
+                start,
+                name,
+                r#type,
+                ..
+            } => {
+                self.aliasing.push(name.clone());
+                *r#type = self.resolve_type(r#type, start);
+                self.aliasing.pop();
+
+                self.aliases.insert(name.clone(), statement.clone());
+            }
+            _ => {}
+        }
+
+        if let parser::statements::Statement::TypeDeclaration { name, generics, .. } = statement
+            && generics.is_empty()
+        {
+            self.types.insert(name.clone(), statement.clone());
+        }
+    }
+
+    /// Resolves a written type, generic types become the name of their instance -> Box<int>
+    fn resolve_type(
+        &mut self,
+        r#type: &internals::types::Types,
+        start: &internals::Position,
+    ) -> internals::types::Types {
+        match r#type {
+            internals::types::Types::Custom(name) if name == "Self" => match &self.self_type {
+                Some(self_type) => internals::types::Types::Custom(self_type.clone()),
+                None => {
+                    self.error("\"Self\" -> Outside of a type".to_string(), start);
+                    r#type.clone()
+                }
+            },
+            internals::types::Types::Custom(name) if self.types.contains_key(name) => {
+                r#type.clone()
+            }
+            internals::types::Types::Custom(name) if self.aliasing.contains(name) => {
+                self.error(
+                    format!("\"{}\" -> A type alias can't refer to itself", name),
+                    start,
+                );
+                internals::types::Types::Unknown
+            }
+            internals::types::Types::Custom(name) if self.aliases.contains_key(name) => {
+                let (aliased, distinct): (internals::types::Types, bool) = match &self.aliases[name]
+                {
+                    parser::statements::Statement::TypeAlias {
+                        r#type, distinct, ..
+                    } => (r#type.clone(), *distinct),
+                    _ => {
+                        return r#type.clone();
+                    }
+                };
+
+                // A distinct type keeps its name, its underlying type is only resolved for the
+                // aliases that refer to themselves through it
+                if distinct && self.aliasing.is_empty() {
+                    return r#type.clone();
+                }
+
+                self.aliasing.push(name.clone());
+                let aliased: internals::types::Types = self.resolve_type(&aliased, start);
+                self.aliasing.pop();
+
+                // It refers to itself through another alias
+                if !aliased.is_known() {
+                    internals::types::Types::Unknown
+                } else if distinct {
+                    r#type.clone()
+                } else {
+                    internals::types::Types::Alias(name.clone(), Box::new(aliased))
+                }
+            }
+            internals::types::Types::Custom(name) if self.type_templates.contains_key(name) => {
+                self.error(format!("\"{}\" -> Missing type arguments", name), start);
+                r#type.clone()
+            }
+            internals::types::Types::Custom(name) => {
+                self.error(format!("\"{}\" -> Unknown type", name), start);
+                r#type.clone()
+            }
+            internals::types::Types::Generic(name, arguments) => {
+                let arguments: Vec<internals::types::Types> = arguments
+                    .iter()
+                    .map(|argument| self.resolve_type(argument, start))
+                    .collect();
+
+                if !arguments.iter().all(internals::types::Types::is_known) {
+                    return r#type.clone();
+                }
+
+                if !self.type_templates.contains_key(name) {
+                    if self.types.contains_key(name) {
+                        self.error(format!("\"{}\" -> Not a generic type", name), start);
+                    } else {
+                        self.error(format!("\"{}\" -> Unknown type", name), start);
+                    }
+
+                    return r#type.clone();
+                }
+
+                match self.instantiate_type(name, arguments, start) {
+                    Some(instance) => internals::types::Types::Custom(instance),
+                    None => r#type.clone(),
+                }
+            }
+            internals::types::Types::Array(element, size) => {
+                internals::types::Types::Array(Box::new(self.resolve_type(element, start)), *size)
+            }
+            internals::types::Types::Slice(element) => {
+                internals::types::Types::Slice(Box::new(self.resolve_type(element, start)))
+            }
+            internals::types::Types::Optional(r#type) => {
+                internals::types::Types::Optional(Box::new(self.resolve_type(r#type, start)))
+            }
+            internals::types::Types::Result(r#type) => {
+                internals::types::Types::Result(Box::new(self.resolve_type(r#type, start)))
+            }
+            internals::types::Types::Tuple(types) => internals::types::Types::Tuple(
+                types
+                    .iter()
+                    .map(|r#type| self.resolve_type(r#type, start))
+                    .collect(),
+            ),
+            internals::types::Types::Function(params, r#type) => internals::types::Types::Function(
+                params
+                    .iter()
+                    .map(|param| self.resolve_type(param, start))
+                    .collect(),
+                Box::new(self.resolve_type(r#type, start)),
+            ),
+            _ => r#type.clone(),
+        }
+    }
+
+    /// Looks a name up from the innermost scope to the globals.
+    fn lookup(&self, name: &str) -> Option<&Symbol> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn lookup_mut(&mut self, name: &str) -> Option<&mut Symbol> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+    }
+
+    /// Infers the type of a declaration from its initializer.
+    fn infer_type(
+        &mut self,
+        name: &str,
+        value: &parser::statements::Expression,
+        start: &internals::Position,
+    ) -> internals::types::Types {
+        let r#type: Option<internals::types::Types> = match value {
+            // The return type of an arrow function comes from its expression
+            parser::statements::Expression::Lambda {
+                params,
+                r#type: internals::types::Types::Unknown,
+                body,
+                ..
+            } => self.lambda_return_type(params, body).map(|r#type| {
+                internals::types::Types::Function(
+                    params.iter().map(|param| param.r#type.clone()).collect(),
+                    Box::new(r#type),
+                )
+            }),
+            // The blocks of a global get their types from its analysis, after its declaration
+            parser::statements::Expression::If { .. }
+            | parser::statements::Expression::Block { .. }
+            | parser::statements::Expression::Loop { .. } => match self.expression_type(value) {
+                Some(r#type) if r#type.is_known() => Some(r#type),
+                _ => {
+                    let mut value: parser::statements::Expression = value.clone();
+                    self.analyze_expression(&mut value, start);
+                    self.expression_type(&value)
+                }
+            },
+            _ => self.expression_type(value),
+        };
+
+        match r#type {
+            Some(r#type)
+                if r#type.is_known()
+                    && !matches!(
+                        r#type,
+                        internals::types::Types::Void | internals::types::Types::Null
+                    ) =>
+            {
+                self.record_inferred(self.instance.clone(), start, r#type.clone());
+                r#type
+            }
+            _ => {
+                self.error(
+                    format!(
+                        "\"{}\" -> Can't infer the type, add a type annotation",
+                        name
+                    ),
+                    start,
+                );
+                internals::types::Types::Unknown
+            }
+        }
+    }
+
+    /// Infers the type of a variable declared without a value from its first assignment.
+    fn infer_from_assignment(
+        &mut self,
+        target: &parser::statements::Expression,
+        value: &parser::statements::Expression,
+    ) {
+        let name: &String = match target {
+            parser::statements::Expression::Identifier(name) => name,
+            _ => {
+                return;
+            }
+        };
+
+        let start: internals::Position = match self.lookup(name) {
+            Some(Symbol {
+                start: Some(start), ..
+            }) => start.clone(),
+            _ => {
+                return;
+            }
+        };
+
+        if let Some(r#type) = self.expression_type(value)
+            && r#type.is_known()
+            && r#type != internals::types::Types::Void
+        {
+            // A global assigned inside a generic instance doesn't belong to it
+            let instance: Option<String> = if self.scopes[1..]
+                .iter()
+                .any(|scope| scope.contains_key(name))
+            {
+                self.instance.clone()
+            } else {
+                None
+            };
+
+            self.record_inferred(instance, &start, r#type.clone());
+
+            if let Some(symbol) = self.lookup_mut(name) {
+                symbol.r#type = r#type;
+                symbol.start = None;
+            }
+        }
+    }
+
+    /// Infers the return type of an arrow function from the expression it returns.
+    fn lambda_return_type(
+        &mut self,
+        params: &[parser::statements::FuncParam],
+        body: &[parser::statements::Statement],
+    ) -> Option<internals::types::Types> {
+        let expression: &parser::statements::Expression = match body {
+            [
+                parser::statements::Statement::Return {
+                    expression: Some(expression),
+                    ..
+                },
+            ] => expression,
+            _ => {
+                return None;
+            }
+        };
+
+        self.push_scope();
+
+        for param in params.iter() {
+            self.declare(&param.name, param.r#type.clone(), false);
+        }
+
+        let r#type: Option<internals::types::Types> = self.expression_type(expression);
+        self.scopes.pop();
+
+        r#type.filter(internals::types::Types::is_known)
+    }
+
+    fn record_inferred(
+        &mut self,
+        instance: Option<String>,
+        start: &internals::Position,
+        r#type: internals::types::Types,
+    ) {
+        match self
+            .inferred
+            .iter_mut()
+            .find(|(other, position, _)| *other == instance && position == start)
+        {
+            Some((_, _, inferred)) => *inferred = r#type,
+            None => self.inferred.push((instance, start.clone(), r#type)),
+        }
+    }
+
+    /// The type inferred for a declaration of the generic instance being analyzed, or of the
+    /// program outside of them.
+    fn inferred_type(&self, start: &internals::Position) -> Option<internals::types::Types> {
+        self.inferred
+            .iter()
+            .find(|(instance, position, _)| *instance == self.instance && position == start)
+            .map(|(_, _, r#type)| r#type.clone())
+    }
+
+    /// Reports the variables of a scope that were never given a type.
+    fn check_inferred_scope(&mut self, index: usize) {
+        let mut pending: Vec<(String, internals::Position)> = match self.scopes.get(index) {
+            Some(scope) => scope
+                .iter()
+                .filter_map(|(name, symbol)| {
+                    symbol
+                        .start
+                        .as_ref()
+                        .map(|start| (name.clone(), start.clone()))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        pending.sort_by_key(|(_, start)| (start.line, start.column));
+
+        for (name, start) in pending {
+            self.error(
+                format!(
+                    "\"{}\" -> Can't infer the type, add a type annotation",
+                    name
+                ),
+                &start,
+            );
+        }
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.check_inferred_scope(self.scopes.len() - 1);
+        self.scopes.pop();
+    }
+
+    /// Replaces the missing annotations of the declarations by their inferred types.
+    fn annotate_statement(&self, statement: &mut parser::statements::Statement) {
+        match statement {
+            parser::statements::Statement::VariableDeclaration {
+                start,
+                r#type,
+                value,
+                ..
+            } => {
+                self.annotate_declaration(start, r#type);
+
+                if let Some(value) = value {
+                    self.annotate_expression(value);
+                }
+            }
+            parser::statements::Statement::ConstantDeclaration {
+                start,
+                r#type,
+                value,
+                ..
+            }
+            | parser::statements::Statement::Destructure {
+                start,
+                r#type,
+                value,
+                ..
+            } => {
+                self.annotate_declaration(start, r#type);
+                self.annotate_expression(value);
+            }
+            parser::statements::Statement::Program { body, .. } => {
+                self.annotate_block(Some(body.as_mut()))
+            }
+            parser::statements::Statement::TypeDeclaration {
+                methods, statics, ..
+            } => {
+                self.annotate_block(Some(methods));
+                self.annotate_block(Some(statics));
+            }
+            parser::statements::Statement::FunctionDeclaration { params, body, .. } => {
+                for default in params
+                    .iter_mut()
+                    .flatten()
+                    .filter_map(|param| param.default.as_mut())
+                {
+                    self.annotate_expression(default);
+                }
+
+                self.annotate_block(body.as_deref_mut())
+            }
+            parser::statements::Statement::Else { body, .. }
+            | parser::statements::Statement::Loop { body, .. }
+            | parser::statements::Statement::Defer { body, .. } => {
+                self.annotate_block(body.as_deref_mut())
+            }
+            parser::statements::Statement::While {
+                condition: expression,
+                body,
+                ..
+            }
+            | parser::statements::Statement::ForIn {
+                iterable: expression,
+                body,
+                ..
+            } => {
+                self.annotate_expression(expression);
+                self.annotate_block(body.as_deref_mut())
+            }
+            parser::statements::Statement::Try { body, catch, .. } => {
+                self.annotate_block(body.as_deref_mut());
+                self.annotate_block(catch.as_deref_mut());
+            }
+            parser::statements::Statement::If {
+                condition,
+                body,
+                alternate,
+                ..
+            }
+            | parser::statements::Statement::ElseIf {
+                condition,
+                body,
+                alternate,
+                ..
+            } => {
+                self.annotate_expression(condition);
+                self.annotate_block(body.as_deref_mut());
+                self.annotate_block(alternate.as_deref_mut());
+            }
+            parser::statements::Statement::For {
+                variable,
+                condition,
+                variable_update,
+                body,
+                ..
+            } => {
+                if let Some(variable) = variable {
+                    self.annotate_statement(variable);
+                }
+
+                if let Some(condition) = condition {
+                    self.annotate_expression(condition);
+                }
+
+                if let Some(variable_update) = variable_update {
+                    self.annotate_statement(variable_update);
+                }
+
+                self.annotate_block(body.as_deref_mut());
+            }
+            parser::statements::Statement::VariableAlteration { target, value, .. } => {
+                self.annotate_expression(target);
+
+                if let Some(value) = value {
+                    self.annotate_expression(value);
+                }
+            }
+            parser::statements::Statement::Break {
+                value: Some(expression),
+                ..
+            }
+            | parser::statements::Statement::Return {
+                expression: Some(expression),
+                ..
+            }
+            | parser::statements::Statement::Throw {
+                value: expression, ..
+            }
+            | parser::statements::Statement::Expression { expression, .. }
+            | parser::statements::Statement::FunctionCall {
+                call: expression, ..
+            } => self.annotate_expression(expression),
+            _ => {}
+        }
+    }
+
+    fn annotate_declaration(
+        &self,
+        start: &internals::Position,
+        r#type: &mut internals::types::Types,
+    ) {
+        if *r#type == internals::types::Types::Unknown
+            && let Some(inferred) = self.inferred_type(start)
+        {
+            *r#type = inferred;
+        }
+    }
+
+    fn annotate_block(&self, block: Option<&mut Vec<parser::statements::Statement>>) {
+        for statement in block.into_iter().flat_map(|block| block.iter_mut()) {
+            self.annotate_statement(statement);
+        }
+    }
+
+    /// Annotates the declarations inside the blocks and functions an expression holds.
+    fn annotate_expression(&self, expression: &mut parser::statements::Expression) {
+        match expression {
+            parser::statements::Expression::Binary { left, right, .. }
+            | parser::statements::Expression::Logical { left, right, .. }
+            | parser::statements::Expression::Range {
+                from: left,
+                to: right,
+                ..
+            } => {
+                self.annotate_expression(left);
+                self.annotate_expression(right);
+            }
+            parser::statements::Expression::Unary { operand: value, .. }
+            | parser::statements::Expression::Member { object: value, .. }
+            | parser::statements::Expression::OptionalMember { object: value, .. }
+            | parser::statements::Expression::StaticMember { r#type: value, .. }
+            | parser::statements::Expression::Spread { value, .. }
+            | parser::statements::Expression::NamedArgument { value, .. }
+            | parser::statements::Expression::Propagate { value, .. }
+            | parser::statements::Expression::Cast { value, .. } => self.annotate_expression(value),
+            parser::statements::Expression::ArrayLiteral { elements } => {
+                for element in elements.iter_mut().flat_map(|elements| elements.iter_mut()) {
+                    self.annotate_expression(element);
+                }
+            }
+            parser::statements::Expression::Tuple { elements } => {
+                for element in elements.iter_mut() {
+                    self.annotate_expression(element);
+                }
+            }
+            parser::statements::Expression::ArrayAcess(acess) => self.annotate_acess(acess),
+            parser::statements::Expression::Call {
+                callee, arguments, ..
+            } => {
+                self.annotate_expression(callee);
+
+                for argument in arguments
+                    .iter_mut()
+                    .flat_map(|arguments| arguments.iter_mut())
+                {
+                    self.annotate_expression(argument);
+                }
+            }
+            parser::statements::Expression::StructLiteral { fields, .. } => {
+                for (_, value) in fields.iter_mut() {
+                    self.annotate_expression(value);
+                }
+            }
+            parser::statements::Expression::Block { body, value, .. } => {
+                self.annotate_block(Some(body.as_mut()));
+
+                if let Some(value) = value {
+                    self.annotate_expression(value);
+                }
+            }
+            parser::statements::Expression::Loop { body, .. } => {
+                self.annotate_block(Some(body.as_mut()))
+            }
+            parser::statements::Expression::If {
+                condition,
+                body,
+                alternate,
+                ..
+            } => {
+                self.annotate_expression(condition);
+                self.annotate_expression(body);
+
+                if let Some(alternate) = alternate {
+                    self.annotate_expression(alternate);
+                }
+            }
+            parser::statements::Expression::Lambda { params, body, .. } => {
+                for default in params.iter_mut().filter_map(|param| param.default.as_mut()) {
+                    self.annotate_expression(default);
+                }
+
+                self.annotate_block(Some(body.as_mut()))
+            }
+            parser::statements::Expression::Identifier(_)
+            | parser::statements::Expression::Literal { .. } => {}
+        }
+    }
+
+    fn annotate_acess(&self, acess: &mut parser::statements::ArrayAcess) {
+        match acess {
+            parser::statements::ArrayAcess::Acess { array, index } => {
+                self.annotate_expression(array);
+                self.annotate_expression(index);
+            }
+            parser::statements::ArrayAcess::NestedAcess { acess, index } => {
+                self.annotate_acess(acess);
+                self.annotate_expression(index);
+            }
+        }
+    }
+
+    fn analyze_block(&mut self, block: Option<&mut Vec<parser::statements::Statement>>) {
+        if let Some(block) = block {
+            self.push_scope();
+
+            for statement in block.iter_mut() {
+                self.analyze_statement(statement);
+            }
+
+            self.pop_scope();
+        }
+    }
+
+    /// Analyzes the body of a loop, returns the type of the values it's left with.
+    fn analyze_loop_body(
+        &mut self,
+        label: Option<&String>,
+        valued: bool,
+        body: Option<&mut Vec<parser::statements::Statement>>,
+        start: &internals::Position,
+    ) -> Option<internals::types::Types> {
+        // A break to the label could only reach one of them
+        if let Some(label) = label
+            && self
+                .loops
+                .iter()
+                .any(|other| other.label.as_ref() == Some(label))
+        {
+            self.error(
+                format!(
+                    "\"{}\" -> Already the label of a loop around this one",
+                    label
+                ),
+                start,
+            );
+        }
+
+        self.loops.push(Loop {
+            label: label.cloned(),
+            valued,
+            r#type: None,
+        });
+        self.analyze_block(body);
+
+        self.loops.pop().and_then(|r#loop| r#loop.r#type)
+    }
+
+    /// Returns the loop a break or continue jumps out of, the innermost one or the one with the label.
+    fn jump_target(
+        &mut self,
+        keyword: &str,
+        label: Option<&String>,
+        start: &internals::Position,
+    ) -> Option<usize> {
+        let target: Option<usize> = match label {
+            Some(label) => self
+                .loops
+                .iter()
+                .rposition(|r#loop| r#loop.label.as_ref() == Some(label)),
+            None => self.loops.len().checked_sub(1),
+        };
+
+        match (target, label) {
+            (Some(index), _) => Some(index),
+            (None, Some(label)) => {
+                self.error(format!("\"{}\" -> Undefined label", label), start);
+                None
+            }
+            (None, None) => {
+                self.error(format!("\"{}\" -> Outside of a loop", keyword), start);
+                None
+            }
+        }
+    }
+
+    /// Checks that the value of a break fits the loop it leaves, all of them give the same type.
+    fn analyze_break_value(
+        &mut self,
+        index: usize,
+        value: Option<&parser::statements::Expression>,
+        start: &internals::Position,
+    ) {
+        if !self.loops[index].valued {
+            if value.is_some() {
+                self.error(
+                    "\"break\" -> Only a loop used as a value can be left with one".to_string(),
+                    start,
+                );
+            }
+            return;
+        }
+
+        let found: internals::types::Types = match value {
+            Some(value) => match self.expression_type(value) {
+                Some(r#type) if r#type.is_known() => r#type,
+                _ => {
+                    return;
+                }
+            },
+            None => internals::types::Types::Void,
+        };
+
+        match self.loops[index].r#type.clone() {
+            None => self.loops[index].r#type = Some(found),
+            Some(expected) if expected == found => {}
+            // Mixed numbers are widened to the largest of them
+            Some(expected) => match expected.common_numeric(&found) {
+                Some(common) => self.loops[index].r#type = Some(common),
+                None => self.error(
+                    format!(
+                        "\"break\" -> Expected the breaks of the loop to have the same type but found {} and {}",
+                        expected, found
+                    ),
+                    start,
+                ),
+            },
+        }
+    }
+
+    fn analyze_statement(&mut self, statement: &mut parser::statements::Statement) {
+        // Globals were resolved and declared before the analysis
+        let local: bool = self.scopes.len() > 1;
+
+        match statement {
+            parser::statements::Statement::VariableDeclaration {
+                start,
+                r#type,
+                value,
+                ..
+            } => {
+                if local {
+                    *r#type = self.resolve_type(r#type, start);
+                }
+
+                if let Some(value) = value {
+                    self.analyze_initializer(r#type, value, start);
+                }
+
+                if local {
+                    self.declare_statement(statement)
+                }
+            }
+            parser::statements::Statement::ConstantDeclaration {
+                start,
+                r#type,
+                value,
+                ..
+            }
+            | parser::statements::Statement::Destructure {
+                start,
+                r#type,
+                value,
+                ..
+            } => {
+                if local {
+                    *r#type = self.resolve_type(r#type, start);
+                }
+
+                self.analyze_initializer(r#type, value, start);
+
+                if local {
+                    self.declare_statement(statement)
+                }
+            }
+            // Only the instances of a generic function are analyzed
+            parser::statements::Statement::FunctionDeclaration { generics, .. }
+                if !generics.is_empty() => {}
+            parser::statements::Statement::FunctionDeclaration {
+                start,
+                name,
+                params,
+                r#type,
+                body,
+                ..
+            } => {
+                let return_type: Option<internals::types::Types> =
+                    self.return_type.replace(r#type.success());
+                let throws: bool = std::mem::replace(
+                    &mut self.throws,
+                    matches!(r#type, internals::types::Types::Result(_)),
+                );
+                self.push_scope();
+
+                // The instance a method is called on, static methods don't have one
+                if let Some(self_type) = self.self_type.clone()
+                    && !name.contains("::")
+                {
+                    self.declare("self", internals::types::Types::Custom(self_type), false);
+                }
+
+                for param in params.iter_mut().flatten() {
+                    self.analyze_param(param, start);
+                }
+
+                // A function body never belongs to the loops or try blocks around its declaration
+                let loops: Vec<Loop> = std::mem::take(&mut self.loops);
+                let try_depth: usize = std::mem::take(&mut self.try_depth);
+                self.analyze_block(body.as_deref_mut());
+                self.loops = loops;
+                self.try_depth = try_depth;
+
+                self.pop_scope();
+                self.return_type = return_type;
+                self.throws = throws;
+            }
+            parser::statements::Statement::TypeDeclaration { generics, .. }
+                if !generics.is_empty() => {}
+            parser::statements::Statement::TypeDeclaration {
+                name,
+                methods,
+                statics,
+                ..
+            } => {
+                let self_type: Option<String> = self.self_type.replace(name.clone());
+
+                for method in methods.iter_mut().chain(statics.iter_mut()) {
+                    self.analyze_statement(method);
+                }
+
+                for method in methods.iter() {
+                    self.check_overload_signature(method);
+                }
+
+                self.self_type = self_type;
+            }
+            parser::statements::Statement::If {
+                start,
+                condition,
+                body,
+                alternate,
+            }
+            | parser::statements::Statement::ElseIf {
+                start,
+                condition,
+                body,
+                alternate,
+            } => {
+                self.analyze_expression(condition, start);
+
+                let checked: Vec<String> = Self::null_checked(condition, true);
+                let unchecked: Vec<String> = Self::null_checked(condition, false);
+
+                self.push_scope();
+                self.narrow(&checked);
+                self.analyze_block(body.as_deref_mut());
+                self.pop_scope();
+
+                self.push_scope();
+                self.narrow(&unchecked);
+                self.analyze_block(alternate.as_deref_mut());
+                self.pop_scope();
+
+                // The rest of the block runs only when the check failed -> if x == null { return; }
+                if local
+                    && alternate.is_none()
+                    && body.as_deref().is_some_and(|body| Self::diverges(body))
+                {
+                    self.narrow(&unchecked);
+                }
+            }
+            parser::statements::Statement::Else { body, .. } => {
+                self.analyze_block(body.as_deref_mut())
+            }
+            parser::statements::Statement::While {
+                start,
+                label,
+                condition,
+                body,
+            } => {
+                self.analyze_expression(condition, start);
+
+                let checked: Vec<String> = Self::null_checked(condition, true);
+
+                self.push_scope();
+                self.narrow(&checked);
+                self.analyze_loop_body(label.as_ref(), false, body.as_deref_mut(), start);
+                self.pop_scope();
+            }
+            parser::statements::Statement::Loop { start, label, body } => {
+                self.analyze_loop_body(label.as_ref(), false, body.as_deref_mut(), start);
+            }
+            parser::statements::Statement::For {
+                start,
+                label,
+                variable,
+                condition,
+                variable_update,
+                body,
+            } => {
+                self.push_scope();
+
+                if let Some(variable) = variable {
+                    self.analyze_statement(variable);
+                }
+
+                if let Some(condition) = condition {
+                    self.analyze_expression(condition, start);
+                }
+
+                if let Some(variable_update) = variable_update {
+                    self.analyze_statement(variable_update);
+                }
+
+                self.analyze_loop_body(label.as_ref(), false, body.as_deref_mut(), start);
+
+                self.pop_scope();
+            }
+            parser::statements::Statement::ForIn {
+                start,
+                label,
+                name,
+                iterable,
+                body,
+            } => {
+                self.analyze_expression(iterable, start);
+
+                let item: internals::types::Types = match self.expression_type(iterable) {
+                    Some(r#type) if r#type.is_known() => match self.item_type(&r#type) {
+                        Some(item) => item,
+                        None => {
+                            self.error(
+                                format!(
+                                    "\"for\" -> Can't iterate over {}, expected an array, a range, a str or a type with a next() method returning an optional",
+                                    r#type
+                                ),
+                                start,
+                            );
+                            internals::types::Types::Unknown
+                        }
+                    },
+                    _ => internals::types::Types::Unknown,
+                };
+
+                self.push_scope();
+                self.declare(name, item, false);
+                self.analyze_loop_body(label.as_ref(), false, body.as_deref_mut(), start);
+                self.pop_scope();
+            }
+            parser::statements::Statement::VariableAlteration {
+                start,
+                target,
+                operator,
+                value,
+            } => {
+                self.analyze_expression(target, start);
+
+                if let Some(value) = value {
+                    self.analyze_expression(value, start);
+
+                    if *operator == lexer::tokens::TokenKind::OpAssign {
+                        self.infer_from_assignment(target, value);
+
+                        // A narrowed variable can be null again -> x = null
+                        if let parser::statements::Expression::Identifier(name) = &*target
+                            && self
+                                .expression_type(value)
+                                .is_some_and(|r#type| r#type.is_nullable())
+                            && let Some(symbol) = self.lookup_mut(name)
+                            && let Some(declared) = symbol.declared.take()
+                        {
+                            symbol.r#type = declared;
+                        }
+
+                        if let Some(expected) = self.expression_type(target) {
+                            self.check_conversion(&expected, value, start);
+                        }
+                    } else if let Some(binary_operator) = operator.binary_operator()
+                        && let Some(expected) = self.expression_type(target)
+                    {
+                        // The result of the operation is stored back -> x += 1.5 on an int
+                        let result: parser::statements::Expression =
+                            parser::statements::Expression::Binary {
+                                operator: binary_operator,
+                                left: Box::new(target.clone()),
+                                right: Box::new(value.clone()),
+                            };
+
+                        self.check_conversion(&expected, &result, start);
+                    }
+                }
+
+                self.analyze_assignment_target(target, operator, start);
+            }
+            parser::statements::Statement::Return { start, .. } if self.deferred => {
+                self.error("\"return\" -> Inside a defer".to_string(), start);
+            }
+            parser::statements::Statement::Return {
+                start,
+                expression: Some(expression),
+            } => {
+                self.analyze_expression(expression, start);
+
+                if let Some(expected) = self.return_type.clone() {
+                    self.check_conversion(&expected, expression, start);
+                }
+            }
+            parser::statements::Statement::Return {
+                start,
+                expression: None,
+            } => {
+                if let Some(expected) = self.return_type.clone()
+                    && expected.unaliased() != internals::types::Types::Void
+                {
+                    self.error(
+                        format!("\"return\" -> Expected a value of type {}", expected),
+                        start,
+                    );
+                }
+            }
+            parser::statements::Statement::FunctionCall { start, call } => {
+                self.analyze_expression(call, start)
+            }
+            parser::statements::Statement::Expression { start, expression } => match expression {
+                parser::statements::Expression::If { .. } => self.analyze_if(expression, false),
+                _ => self.analyze_expression(expression, start),
+            },
+            parser::statements::Statement::Use { start, module }
+                if !evaluator::BUILTIN_MODULES.contains(&module.as_str())
+                    && !self.sources.contains_key(module) =>
+            {
+                self.error(format!("\"{}\" -> Unknown module", module), start);
+            }
+            parser::statements::Statement::Throw { start, value } => {
+                self.analyze_expression(value, start);
+
+                if !self.throws && self.try_depth == 0 {
+                    self.error(
+                        "\"throw\" -> Outside of a function that throws or a try block".to_string(),
+                        start,
+                    );
+                }
+
+                if let Some(r#type) = self.expression_type(value)
+                    && r#type.is_known()
+                    && !matches!(
+                        r#type,
+                        internals::types::Types::String | internals::types::Types::Error
+                    )
+                {
+                    self.error(
+                        format!("\"throw\" -> Expected str or error but found {}", r#type),
+                        start,
+                    );
+                }
+            }
+            parser::statements::Statement::Try {
+                body, name, catch, ..
+            } => {
+                self.try_depth += 1;
+                self.analyze_block(body.as_deref_mut());
+                self.try_depth -= 1;
+
+                self.push_scope();
+
+                if let Some(name) = name {
+                    self.declare(name, internals::types::Types::Error, false);
+                }
+
+                self.analyze_block(catch.as_deref_mut());
+                self.pop_scope();
+            }
+            parser::statements::Statement::Defer { body, .. } => {
+                // Nothing leaves a deferred body, not even to the loops and try blocks around it
+                let deferred: bool = std::mem::replace(&mut self.deferred, true);
+                let loops: Vec<Loop> = std::mem::take(&mut self.loops);
+                let try_depth: usize = std::mem::take(&mut self.try_depth);
+                let throws: bool = std::mem::take(&mut self.throws);
+
+                self.analyze_block(body.as_deref_mut());
+
+                self.deferred = deferred;
+                self.loops = loops;
+                self.try_depth = try_depth;
+                self.throws = throws;
+            }
+            parser::statements::Statement::Break {
+                start,
+                label,
+                value,
+            } => {
+                if let Some(value) = value {
+                    self.analyze_expression(value, start);
+                }
+
+                if let Some(index) = self.jump_target("break", label.as_ref(), start) {
+                    self.analyze_break_value(index, value.as_ref(), start);
+                }
+            }
+            parser::statements::Statement::Continue { start, label } => {
+                self.jump_target("continue", label.as_ref(), start);
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks that the target of an assignment is a declared variable, not a constant and that
+    /// the operator can be applied to its type.
+    fn analyze_assignment_target(
+        &mut self,
+        target: &parser::statements::Expression,
+        operator: &lexer::tokens::TokenKind,
+        start: &internals::Position,
+    ) {
+        // A global of an imported module -> module.global = value
+        if let parser::statements::Expression::Member { object, member } = target
+            && let parser::statements::Expression::Identifier(module) = object.deref()
+            && self.imports.contains(module)
+            && self.lookup(module).is_none()
+        {
+            if let Some(parser::statements::Statement::ConstantDeclaration { .. }) =
+                self.module_declaration(module, member)
+            {
+                self.error(
+                    format!("\"{}.{}\" -> Cannot assign to a constant", module, member),
+                    start,
+                );
+            }
+
+            return;
+        }
+
+        // A static field is a global named after its type -> Counter::count
+        let root: Option<String> = match target {
+            parser::statements::Expression::StaticMember { .. } => target.path_name(),
+            _ => target.root_identifier().cloned(),
+        };
+
+        if let Some(name) = root {
+            let symbol: Symbol = match self.lookup(&name) {
+                Some(symbol) => symbol.clone(),
+                None => {
+                    self.error(format!("\"{}\" -> Undefined variable", name), start);
+                    return;
+                }
+            };
+
+            if symbol.constant {
+                self.error(
+                    format!("\"{}\" -> Cannot assign to a constant", name),
+                    start,
+                );
+                return;
+            }
+
+            // An overloaded "[]" only reads -> grid[0] = 1
+            if let parser::statements::Expression::ArrayAcess(acess) = target
+                && let Some(internals::types::Types::Custom(r#type)) = match acess {
+                    parser::statements::ArrayAcess::Acess { array, .. } => {
+                        self.expression_type(array)
+                    }
+                    parser::statements::ArrayAcess::NestedAcess { acess, .. } => {
+                        self.array_acess_type(acess)
+                    }
+                }
+                && self.method(&r#type, "[]").is_some()
+            {
+                self.error(
+                    format!(
+                        "\"over []\" -> Cannot assign to an element of {}, it only reads",
+                        r#type
+                    ),
+                    start,
+                );
+                return;
+            }
+
+            // Tuples can't change once they are built
+            if let parser::statements::Expression::Member { object, member } = target
+                && let Some(internals::types::Types::Tuple(_)) = self.expression_type(object)
+            {
+                self.error(
+                    format!("\"{}\" -> Cannot assign to an element of a tuple", member),
+                    start,
+                );
+                return;
+            }
+
+            // The type of a member isn't known here
+            if !matches!(
+                target,
+                parser::statements::Expression::Identifier(_)
+                    | parser::statements::Expression::StaticMember { .. }
+            ) {
+                return;
+            }
+
+            let numeric: bool = symbol.r#type.is_numeric();
+
+            let valid: bool = match operator {
+                lexer::tokens::TokenKind::OpAssign => true,
+                _ if self.overloads(&symbol.r#type, operator) => true,
+                lexer::tokens::TokenKind::OpAddAssign => {
+                    numeric || symbol.r#type == internals::types::Types::String
+                }
+                _ if operator.is_bitwise() => symbol.r#type.is_integer(),
+                _ => numeric,
+            };
+
+            if !valid {
+                self.error(
+                    format!(
+                        "\"{}\" -> Operator {:?} can't be applied to {}",
+                        name, operator, symbol.r#type
+                    ),
+                    start,
+                );
+            }
+        }
+    }
+
+    /// The type of the items a for loop takes from an iterable -> int for a range, char for a str
+    fn item_type(&self, r#type: &internals::types::Types) -> Option<internals::types::Types> {
+        match r#type {
+            internals::types::Types::Range(item) => Some(*item.clone()),
+            internals::types::Types::Array(element, _)
+            | internals::types::Types::Slice(element) => Some(*element.clone()),
+            internals::types::Types::String => Some(internals::types::Types::Char),
+            // A type can give a separate iterator -> items.iter()
+            internals::types::Types::Custom(name) => match self.method(name, "iter") {
+                Some(parser::statements::Statement::FunctionDeclaration {
+                    r#type: internals::types::Types::Custom(iterator),
+                    ..
+                }) => self.next_type(iterator),
+                _ => self.next_type(name),
+            },
+            _ => None,
+        }
+    }
+
+    /// The type of the items an iterator gives, its next() returns null once it has no more.
+    fn next_type(&self, iterator: &str) -> Option<internals::types::Types> {
+        match self.method(iterator, "next") {
+            Some(parser::statements::Statement::FunctionDeclaration {
+                r#type: internals::types::Types::Optional(item),
+                ..
+            }) => Some(*item.clone()),
+            _ => None,
+        }
+    }
+
+    /// Checks that two integers meet as a common type and that an integer literal fits in the sized
+    /// type of the other side -> u8 + 300
+    fn check_integer_operands(
+        &mut self,
+        left: &parser::statements::Expression,
+        right: &parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        let (left_type, right_type) =
+            match (self.expression_type(left), self.expression_type(right)) {
+                (Some(left), Some(right)) if left.is_integer() && right.is_integer() => {
+                    (left, right)
+                }
+                _ => {
+                    return;
+                }
+            };
+
+        if left_type.common_numeric(&right_type).is_none() {
+            return self.error(
+                format!(
+                    "Can't mix {} and {} in an operation, convert one of them with \"as\"",
+                    left_type, right_type
+                ),
+                start,
+            );
+        }
+
+        for (literal, other) in [(left, &right_type), (right, &left_type)] {
+            self.check_integer_literal(other, literal, start);
+        }
+    }
+
+    /// Checks that an integer literal is in the range of the integer type it's given to.
+    fn check_integer_literal(
+        &mut self,
+        expected: &internals::types::Types,
+        value: &parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        if let Some((min, max)) = expected.integer_range()
+            && value.is_number_literal()
+            && let Some(literal) = self.fold_integer(value)
+            && !(min..=max).contains(&(literal as i128))
+        {
+            self.error(
+                format!("{} is out of the range of {}", literal, expected),
+                start,
+            );
+        }
+    }
+
+    /// Checks the signature of an operator a type overloads -> over +(other: Self) -> Self
+    fn check_overload_signature(&mut self, method: &parser::statements::Statement) {
+        let (start, name, params, r#type) = match method {
+            parser::statements::Statement::FunctionDeclaration {
+                start,
+                name,
+                params,
+                r#type,
+                ..
+            } if !name.starts_with(|c: char| c == '_' || c.is_alphanumeric()) => {
+                (start, name, params, r#type)
+            }
+            _ => {
+                return;
+            }
+        };
+
+        let count: usize = params.as_ref().map_or(0, |params| params.len());
+
+        if count != 1 {
+            self.error(
+                format!(
+                    "\"over {}\" -> Expected one parameter but found {}",
+                    name, count
+                ),
+                start,
+            );
+        }
+
+        if matches!(name.as_str(), "==" | "<" | ">" | "<=" | ">=")
+            && r#type.is_known()
+            && *r#type != internals::types::Types::Bool
+        {
+            self.error(
+                format!(
+                    "\"over {}\" -> Expected bool as the return type but found {}",
+                    name, r#type
+                ),
+                start,
+            );
+        }
+    }
+
+    /// Checks an operator applied to an instance of a type against the overload of the type,
+    /// returns false when the operator isn't overloaded and works as usual.
+    fn analyze_overload(
+        &mut self,
+        operator: &lexer::tokens::TokenKind,
+        left: &parser::statements::Expression,
+        right: &parser::statements::Expression,
+        start: &internals::Position,
+    ) -> bool {
+        let name: &str = match operator.overload() {
+            Some(name) => name,
+            None => {
+                return false;
+            }
+        };
+
+        let (left_type, right_type) = (self.expression_type(left), self.expression_type(right));
+
+        let r#type: String = match (&left_type, &right_type) {
+            (Some(internals::types::Types::Custom(r#type)), _)
+                if self.types.contains_key(r#type) =>
+            {
+                r#type.clone()
+            }
+            // The left operand chooses the overload -> point * 2
+            (Some(left_type), Some(internals::types::Types::Custom(r#type)))
+                if name != "==" && left_type.is_known() && self.types.contains_key(r#type) =>
+            {
+                self.error(
+                    format!(
+                        "Operator {} isn't defined for {} and {}, the overloads of {} take it on the left",
+                        name, left_type, r#type, r#type
+                    ),
+                    start,
+                );
+                return true;
+            }
+            _ => {
+                return false;
+            }
+        };
+
+        // Instances are still equal by their fields and never null, unless they overload "=="
+        if name == "=="
+            && (right_type == Some(internals::types::Types::Null)
+                || self.method(&r#type, name).is_none())
+        {
+            return false;
+        }
+
+        self.analyze_overload_call(&r#type, name, right, start);
+        true
+    }
+
+    /// Checks that a type overloads an operator, that it's accessible from here and the operand
+    /// it's given.
+    fn analyze_overload_call(
+        &mut self,
+        r#type: &str,
+        name: &str,
+        operand: &parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        let (params, visibility) = match self.method(r#type, name) {
+            Some(parser::statements::Statement::FunctionDeclaration {
+                params, visibility, ..
+            }) => (params.clone().unwrap_or_default(), visibility.clone()),
+            _ => {
+                self.error(
+                    format!(
+                        "Operator {} isn't defined for {}, declare it with \"over {}\"",
+                        name, r#type, name
+                    ),
+                    start,
+                );
+                return;
+            }
+        };
+
+        if let Some(param) = params.first() {
+            self.check_conversion(&param.r#type, operand, start);
+        }
+
+        // The methods of a type can use all of its operators
+        if self.self_type.as_deref() == Some(r#type) {
+            return;
+        }
+
+        match visibility {
+            parser::statements::Visibility::Public => {}
+            parser::statements::Visibility::Private => self.error(
+                format!("\"over {}\" -> Private member of {}", name, r#type),
+                start,
+            ),
+            parser::statements::Visibility::Protected => self.error(
+                format!("\"over {}\" -> Protected member of {}", name, r#type),
+                start,
+            ),
+        }
+    }
+
+    /// Checks if the type of a value overloads an operator, compound assignments included.
+    fn overloads(
+        &self,
+        r#type: &internals::types::Types,
+        operator: &lexer::tokens::TokenKind,
+    ) -> bool {
+        match (r#type, operator.overload()) {
+            (internals::types::Types::Custom(r#type), Some(name)) => {
+                self.method(r#type, name).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks that the operand of a bitwise operator is an integer.
+    fn check_bitwise_operand(
+        &mut self,
+        operator: &lexer::tokens::TokenKind,
+        r#type: &internals::types::Types,
+        start: &internals::Position,
+    ) {
+        if operator.is_bitwise() && r#type.is_known() && !r#type.is_integer() {
+            self.error(
+                format!(
+                    "Can't use {} in a bitwise operation, expected an integer",
+                    r#type
+                ),
+                start,
+            );
+        }
+    }
+
+    /// Checks the value of a declaration against its declared type.
+    fn analyze_initializer(
+        &mut self,
+        r#type: &internals::types::Types,
+        value: &mut parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        self.analyze_expression(value, start);
+        self.check_conversion(r#type, value, start);
+
+        if let (
+            internals::types::Types::Array(_, size),
+            parser::statements::Expression::ArrayLiteral { elements },
+        ) = (r#type, value)
+        {
+            let length: usize = elements.as_ref().map_or(0, |elements| elements.len());
+
+            if length != *size {
+                self.error(
+                    format!(
+                        "Expected an array of {} elements but found {}",
+                        size, length
+                    ),
+                    start,
+                );
+            }
+        }
+    }
+
+    /// Walks an expression looking for errors that can be found before running it.
+    fn analyze_expression(
+        &mut self,
+        expression: &mut parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        // The name of a call that isn't followed by "?"
+        let mut unhandled: Option<String> = None;
+
+        match expression {
+            // Only the methods called on an instance have one
+            parser::statements::Expression::Identifier(name)
+                if name == "self" && self.self_type.is_some() && self.lookup(name).is_none() =>
+            {
+                self.error(
+                    "\"self\" -> Static members don't have an instance".to_string(),
+                    start,
+                );
+            }
+            parser::statements::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                self.analyze_expression(left, start);
+                self.analyze_expression(right, start);
+
+                // The operand of an overloaded operator is checked against its method
+                if !self.analyze_overload(operator, left, right, start) {
+                    self.check_integer_operands(left, right, start);
+
+                    for operand in [left, right] {
+                        match self.expression_type(operand) {
+                            Some(r#type) if r#type.is_nullable() => {
+                                self.error(
+                                    format!(
+                                        "Can't use {} in an arithmetic operation, check that it isn't null first",
+                                        r#type
+                                    ),
+                                    start,
+                                );
+                            }
+                            Some(r#type) => self.check_bitwise_operand(operator, &r#type, start),
+                            None => {}
+                        }
+                    }
+                }
+            }
+            parser::statements::Expression::Logical {
+                operator,
+                left,
+                right,
+            } => {
+                self.analyze_expression(left, start);
+
+                // The right side only runs after the left one -> x != null and x.size > 0
+                let checked: Vec<String> = match operator {
+                    lexer::tokens::TokenKind::OpAnd => Self::null_checked(left, true),
+                    lexer::tokens::TokenKind::OpOr => Self::null_checked(left, false),
+                    _ => Vec::new(),
+                };
+
+                self.push_scope();
+                self.narrow(&checked);
+                self.analyze_expression(right, start);
+                self.pop_scope();
+
+                self.analyze_overload(operator, left, right, start);
+
+                // The fallback takes the place of a null -> count ?? 0
+                if *operator == lexer::tokens::TokenKind::OpCoalesce
+                    && let (Some(expected), Some(found)) =
+                        (self.expression_type(left), self.expression_type(right))
+                {
+                    self.check_type_conversion(
+                        &expected.non_null().optional(),
+                        &found,
+                        right.is_number_literal(),
+                        start,
+                    );
+                }
+            }
+            parser::statements::Expression::Unary { operator, operand } => {
+                self.analyze_expression(operand, start);
+
+                if let Some(r#type) = self.expression_type(operand) {
+                    self.check_bitwise_operand(operator, &r#type, start);
+                }
+            }
+            parser::statements::Expression::ArrayLiteral { elements } => {
+                for element in elements.iter_mut().flat_map(|elements| elements.iter_mut()) {
+                    self.analyze_expression(element, start);
+                }
+            }
+            parser::statements::Expression::Tuple { elements } => {
+                for element in elements.iter_mut() {
+                    self.analyze_expression(element, start);
+                }
+            }
+            parser::statements::Expression::Range { from, to, .. } => {
+                self.analyze_expression(from, start);
+                self.analyze_expression(to, start);
+
+                let mut bounds: Vec<internals::types::Types> = Vec::new();
+
+                for bound in [from, to] {
+                    match self.expression_type(bound) {
+                        Some(r#type) if r#type.is_known() && !r#type.is_integer() => self.error(
+                            format!("Expected integer bounds for a range but found {}", r#type),
+                            start,
+                        ),
+                        Some(r#type) if r#type.is_known() => bounds.push(r#type),
+                        _ => {}
+                    }
+                }
+
+                if let [from, to] = bounds.as_slice()
+                    && from != to
+                    && from.common_numeric(to).is_none()
+                {
+                    self.error(
+                        format!(
+                            "Can't mix {} and {} in the bounds of a range, convert one of them with \"as\"",
+                            from, to
+                        ),
+                        start,
+                    );
+                }
+            }
+            parser::statements::Expression::ArrayAcess(acess) => {
+                self.analyze_array_acess(acess, start)
+            }
+            parser::statements::Expression::StaticMember {
+                r#type: object,
+                member,
+            } => match object.as_mut() {
+                parser::statements::Expression::Identifier(module)
+                    if self.imports.contains(module) && self.lookup(module).is_none() =>
+                {
+                    self.analyze_module_item(module, member, start)
+                }
+                _ => self.analyze_static_member(object, member, start),
+            },
+            parser::statements::Expression::Member { object, member } => match object.as_mut() {
+                parser::statements::Expression::Identifier(module)
+                    if self.imports.contains(module) && self.lookup(module).is_none() =>
+                {
+                    self.analyze_module_item(module, member, start)
+                }
+                _ => {
+                    self.analyze_expression(object, start);
+                    self.analyze_member(object, member, false, start);
+                }
+            },
+            parser::statements::Expression::OptionalMember { object, member } => {
+                self.analyze_expression(object, start);
+                self.analyze_member(object, member, true, start);
+            }
+            parser::statements::Expression::Call {
+                callee,
+                arguments,
+                starts,
+            } => {
+                // The calls inside this one aren't handled by its "?"
+                let handled: bool = std::mem::take(&mut self.handled);
+
+                self.analyze_expression(callee, start);
+
+                for (index, argument) in arguments
+                    .iter_mut()
+                    .flat_map(|arguments| arguments.iter_mut())
+                    .enumerate()
+                {
+                    self.analyze_expression(argument, starts.get(index).unwrap_or(start));
+                }
+
+                // A value that isn't a function -> count(1)
+                if !matches!(
+                    callee.as_ref(),
+                    parser::statements::Expression::Identifier(name) if self.lookup(name).is_none()
+                ) && !matches!(
+                    callee.as_ref(),
+                    parser::statements::Expression::Member { .. }
+                        | parser::statements::Expression::OptionalMember { .. }
+                ) && let Some(r#type) = self.expression_type(callee)
+                    && r#type.is_known()
+                    && !matches!(r#type, internals::types::Types::Function(..))
+                {
+                    self.error(
+                        format!(
+                            "\"{}\" -> {} isn't a function",
+                            callee.path_name().unwrap_or_else(|| "function".to_string()),
+                            r#type
+                        ),
+                        start,
+                    );
+                }
+
+                self.analyze_generic_call(callee, arguments.as_deref(), start);
+
+                let arguments: &[parser::statements::Expression] = match arguments {
+                    Some(arguments) => arguments,
+                    None => &[],
+                };
+                self.analyze_arguments(callee, arguments, starts, start);
+
+                if !handled {
+                    unhandled = Some(callee.path_name().unwrap_or_else(|| "function".to_string()));
+                }
+            }
+            parser::statements::Expression::Propagate { start, value } => {
+                if !self.throws && self.try_depth == 0 {
+                    self.error(
+                        "\"?\" -> Outside of a function that throws or a try block".to_string(),
+                        start,
+                    );
+                }
+
+                self.handled =
+                    matches!(value.as_ref(), parser::statements::Expression::Call { .. });
+                self.analyze_expression(value, start);
+                self.handled = false;
+
+                if let Some(r#type) = self.expression_type(value)
+                    && r#type.is_known()
+                    && !matches!(r#type, internals::types::Types::Result(_))
+                {
+                    self.error(
+                        format!(
+                            "\"?\" -> Expected a call that can throw but found {}",
+                            r#type
+                        ),
+                        start,
+                    );
+                }
+            }
+            parser::statements::Expression::Block {
+                body,
+                value,
+                r#type,
+            } => {
+                self.push_scope();
+
+                for statement in body.iter_mut() {
+                    self.analyze_statement(statement);
+                }
+
+                *r#type = match value {
+                    Some(value) => {
+                        self.analyze_expression(value, start);
+                        self.expression_type(value)
+                            .unwrap_or(internals::types::Types::Unknown)
+                    }
+                    None => internals::types::Types::Void,
+                };
+
+                self.pop_scope();
+            }
+            parser::statements::Expression::Loop {
+                label,
+                body,
+                r#type,
+            } => {
+                *r#type = self
+                    .analyze_loop_body(label.as_ref(), true, Some(body.as_mut()), start)
+                    .unwrap_or(internals::types::Types::Void);
+            }
+            parser::statements::Expression::If { .. } => self.analyze_if(expression, true),
+            parser::statements::Expression::NamedArgument { value, .. }
+            | parser::statements::Expression::Spread { value, .. } => {
+                self.analyze_expression(value, start)
+            }
+            parser::statements::Expression::Cast { value, r#type } => {
+                self.analyze_expression(value, start);
+                *r#type = self.resolve_type(r#type, start);
+
+                if let Some(from) = self.written_type(value)
+                    && from.is_known()
+                    && self.conversion(&from, r#type) == internals::types::Conversion::Invalid
+                {
+                    self.error(format!("Can't convert {} to {}", from, r#type), start);
+                }
+            }
+            parser::statements::Expression::StructLiteral { name, fields } => {
+                for (_, value) in fields.iter_mut() {
+                    self.analyze_expression(value, start);
+                }
+
+                self.analyze_struct_literal(name, fields, start);
+
+                for (field, value) in fields.iter() {
+                    if let Some(declared) = self.field(name, field) {
+                        let expected: internals::types::Types = declared.r#type.clone();
+                        self.check_conversion(&expected, value, start);
+                    }
+                }
+            }
+            parser::statements::Expression::Lambda {
+                start,
+                params,
+                r#type,
+                body,
+            } => {
+                for param in params.iter_mut() {
+                    param.r#type = self.resolve_type(&param.r#type, start);
+                }
+
+                if *r#type != internals::types::Types::Unknown {
+                    *r#type = self.resolve_type(r#type, start);
+                }
+
+                self.push_scope();
+
+                for param in params.iter_mut() {
+                    self.analyze_param(param, start);
+                }
+
+                // A function body never belongs to the loops, try blocks or defer around it
+                let loops: Vec<Loop> = std::mem::take(&mut self.loops);
+                let try_depth: usize = std::mem::take(&mut self.try_depth);
+                let deferred: bool = std::mem::take(&mut self.deferred);
+                let return_type: Option<internals::types::Types> = std::mem::replace(
+                    &mut self.return_type,
+                    Some(r#type.success()).filter(internals::types::Types::is_known),
+                );
+                let throws: bool = std::mem::replace(
+                    &mut self.throws,
+                    matches!(r#type, internals::types::Types::Result(_)),
+                );
+
+                for statement in body.iter_mut() {
+                    self.analyze_statement(statement);
+                }
+
+                self.throws = throws;
+                self.return_type = return_type;
+                self.deferred = deferred;
+                self.try_depth = try_depth;
+                self.loops = loops;
+                self.pop_scope();
+
+                if *r#type == internals::types::Types::Unknown {
+                    *r#type = match self.lambda_return_type(params, body) {
+                        Some(r#type) => r#type,
+                        None => {
+                            self.error(
+                                "Can't infer the return type, add a type annotation".to_string(),
+                                start,
+                            );
+                            internals::types::Types::Unknown
+                        }
+                    };
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(name) = unhandled
+            && let Some(internals::types::Types::Result(_)) = self.expression_type(expression)
+        {
+            self.error(
+                format!("\"{}\" -> Can throw, propagate its error with \"?\"", name),
+                start,
+            );
+        }
+    }
+
+    /// Declares a parameter, after checking its default value against its type.
+    fn analyze_param(
+        &mut self,
+        param: &mut parser::statements::FuncParam,
+        start: &internals::Position,
+    ) {
+        if let Some(default) = &mut param.default {
+            self.analyze_expression(default, start);
+            self.check_conversion(&param.r#type, default, start);
+        }
+
+        self.declare(&param.name, param.r#type.clone(), false);
+    }
+
+    /// Analyzes an if expression, used as a value it needs an else and branches of the same type.
+    fn analyze_if(&mut self, expression: &mut parser::statements::Expression, value: bool) {
+        let (start, condition, body, alternate) = match expression {
+            parser::statements::Expression::If {
+                start,
+                condition,
+                body,
+                alternate,
+            } => (start.clone(), condition, body, alternate),
+            _ => {
+                return;
+            }
+        };
+
+        self.analyze_expression(condition, &start);
+
+        self.push_scope();
+        self.narrow(&Self::null_checked(condition, true));
+        self.analyze_expression(body, &start);
+        self.pop_scope();
+
+        let alternate: &mut parser::statements::Expression = match alternate {
+            Some(alternate) => alternate,
+            None => {
+                if value {
+                    self.error(
+                        "\"if\" -> Used as a value without an else".to_string(),
+                        &start,
+                    );
+                }
+
+                return;
+            }
+        };
+
+        self.push_scope();
+        self.narrow(&Self::null_checked(condition, false));
+
+        match alternate {
+            parser::statements::Expression::If { .. } => self.analyze_if(alternate, value),
+            _ => self.analyze_expression(alternate, &start),
+        }
+
+        self.pop_scope();
+
+        if !value {
+            return;
+        }
+
+        if let (Some(left), Some(right)) =
+            (self.expression_type(body), self.expression_type(alternate))
+            && left.is_known()
+            && right.is_known()
+            && left != right
+            && left.common_numeric(&right).is_none()
+        {
+            self.error(
+                format!(
+                    "\"if\" -> Expected the branches to have the same type but found {} and {}",
+                    left, right
+                ),
+                &start,
+            );
+        }
+    }
+
+    /// Returns the variables a condition proves aren't null when it has the given outcome
+    /// -> x when x != null is true or when x == null is false
+    fn null_checked(condition: &parser::statements::Expression, outcome: bool) -> Vec<String> {
+        match condition {
+            parser::statements::Expression::Logical {
+                operator,
+                left,
+                right,
+            } => match (operator, outcome) {
+                (lexer::tokens::TokenKind::OpNeq, true)
+                | (lexer::tokens::TokenKind::OpEq, false) => match (left.deref(), right.deref()) {
+                    (
+                        parser::statements::Expression::Identifier(name),
+                        parser::statements::Expression::Literal {
+                            r#type: lexer::tokens::TokenKind::Null,
+                            ..
+                        },
+                    )
+                    | (
+                        parser::statements::Expression::Literal {
+                            r#type: lexer::tokens::TokenKind::Null,
+                            ..
+                        },
+                        parser::statements::Expression::Identifier(name),
+                    ) => vec![name.clone()],
+                    _ => Vec::new(),
+                },
+                // Both sides had the outcome -> x != null and y != null
+                (lexer::tokens::TokenKind::OpAnd, true)
+                | (lexer::tokens::TokenKind::OpOr, false) => {
+                    let mut names: Vec<String> = Self::null_checked(left, outcome);
+                    names.extend(Self::null_checked(right, outcome));
+                    names
+                }
+                _ => Vec::new(),
+            },
+            parser::statements::Expression::Unary {
+                operator: lexer::tokens::TokenKind::OpNot,
+                operand,
+            } => Self::null_checked(operand, !outcome),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Declares variables with their non-null types in the innermost scope.
+    fn narrow(&mut self, names: &[String]) {
+        for name in names {
+            if let Some(symbol) = self.lookup(name)
+                && let internals::types::Types::Optional(r#type) = &symbol.r#type
+            {
+                let narrowed: Symbol = Symbol {
+                    r#type: r#type.deref().clone(),
+                    declared: Some(symbol.r#type.clone()),
+                    ..symbol.clone()
+                };
+
+                self.declare_symbol(name, narrowed);
+            }
+        }
+    }
+
+    /// Checks if a block always leaves before its end -> { return; }
+    fn diverges(block: &[parser::statements::Statement]) -> bool {
+        matches!(
+            block.last(),
+            Some(
+                parser::statements::Statement::Return { .. }
+                    | parser::statements::Statement::Throw { .. }
+                    | parser::statements::Statement::Break { .. }
+                    | parser::statements::Statement::Continue { .. }
+            )
+        )
+    }
+
+    /// Checks that a value can be given to a declared type, suggesting a cast when it's needed.
+    fn check_conversion(
+        &mut self,
+        expected: &internals::types::Types,
+        value: &parser::statements::Expression,
+        start: &internals::Position,
+    ) {
+        // The elements of a tuple literal are checked on their own, so number literals still fit
+        if let (
+            internals::types::Types::Tuple(types),
+            parser::statements::Expression::Tuple { elements },
+        ) = (expected, value)
+            && types.len() == elements.len()
+        {
+            for (r#type, element) in types.iter().zip(elements) {
+                self.check_conversion(r#type, element, start);
+            }
+
+            return;
+        }
+
+        // An integer literal fits any integer type that holds its value -> var x: u8 = 200;
+        let integer: internals::types::Types = expected.unaliased().non_null();
+
+        if integer.is_integer() && value.is_number_literal() && self.fold_integer(value).is_some() {
+            return self.check_integer_literal(&integer, value, start);
+        }
+
+        if let Some(found) = self.written_type(value) {
+            self.check_type_conversion(expected, &found, value.is_number_literal(), start);
+        }
+    }
+
+    /// Checks that a value of a type can be given to another type, number literals are doubles
+    /// that also fit a float.
+    fn check_type_conversion(
+        &mut self,
+        written_expected: &internals::types::Types,
+        written_found: &internals::types::Types,
+        literal: bool,
+        start: &internals::Position,
+    ) {
+        // The errors name the types as they are written, aliases included
+        let expected: &internals::types::Types = &written_expected.unaliased();
+        let found: &internals::types::Types = &written_found.unaliased();
+
+        // Null only fits an optional type, which also takes the values of its type
+        match (expected, found) {
+            (internals::types::Types::Optional(_), internals::types::Types::Null) => {
+                return;
+            }
+            (internals::types::Types::Optional(expected), found) => {
+                return self.check_type_conversion(expected, &found.non_null(), literal, start);
+            }
+            (expected, found) if expected.is_known() && found.is_nullable() => {
+                let hint: &str = match found {
+                    internals::types::Types::Null => "",
+                    _ => ", check that it isn't null first",
+                };
+
+                return self.error(
+                    format!(
+                        "Expected {} but found {}{}",
+                        written_expected, written_found, hint
+                    ),
+                    start,
+                );
+            }
+            _ => {}
+        }
+
+        if let (internals::types::Types::Tuple(types), internals::types::Types::Tuple(found)) =
+            (expected, found)
+        {
+            if types.len() != found.len() {
+                return self.error(
+                    format!(
+                        "Expected a tuple of {} elements but found {}",
+                        types.len(),
+                        internals::types::Types::Tuple(found.clone())
+                    ),
+                    start,
+                );
+            }
+
+            for (expected, found) in types.iter().zip(found) {
+                self.check_type_conversion(expected, found, false, start);
+            }
+
+            return;
+        }
+
+        if !expected.is_known() || !found.is_known() {
+            return;
+        }
+
+        let distinct: bool = self.is_distinct(expected) || self.is_distinct(found);
+
+        if !distinct && (!found.is_primitive() || !expected.is_primitive()) {
+            return;
+        }
+
+        match self.conversion(found, expected) {
+            internals::types::Conversion::Identity | internals::types::Conversion::Implicit => {}
+            // -> const x: float = 3.14 * 2;
+            internals::types::Conversion::Explicit
+                if *found == internals::types::Types::Double
+                    && *expected == internals::types::Types::Float
+                    && literal => {}
+            internals::types::Conversion::Explicit => self.error(
+                format!(
+                    "Expected {} but found {}, convert it with \"as {}\"",
+                    written_expected, written_found, written_expected
+                ),
+                start,
+            ),
+            internals::types::Conversion::Invalid => self.error(
+                format!("Expected {} but found {}", written_expected, written_found),
+                start,
+            ),
+        }
+    }
+
+    /// Checks if a type is a distinct type alias -> type UserId = new int;
+    fn is_distinct(&self, r#type: &internals::types::Types) -> bool {
+        matches!(
+            r#type,
+            internals::types::Types::Custom(name) if matches!(
+                self.aliases.get(name),
+                Some(parser::statements::Statement::TypeAlias { distinct: true, .. })
+            )
+        )
+    }
+
+    /// The type a distinct type has the values of -> int for UserId
+    fn underlying_type(&self, r#type: &internals::types::Types) -> internals::types::Types {
+        match r#type {
+            internals::types::Types::Custom(name) if self.is_distinct(r#type) => {
+                match &self.aliases[name] {
+                    parser::statements::Statement::TypeAlias { r#type, .. } => {
+                        self.underlying_type(&r#type.unaliased())
+                    }
+                    _ => r#type.clone(),
+                }
+            }
+            _ => r#type.unaliased(),
+        }
+    }
+
+    /// How a value of a type becomes a value of another type, a distinct type converts like its
+    /// underlying type but only with a cast.
+    fn conversion(
+        &self,
+        from: &internals::types::Types,
+        to: &internals::types::Types,
+    ) -> internals::types::Conversion {
+        if from == to {
+            return internals::types::Conversion::Identity;
+        }
+
+        match self
+            .underlying_type(from)
+            .conversion(&self.underlying_type(to))
+        {
+            internals::types::Conversion::Identity | internals::types::Conversion::Implicit
+                if self.is_distinct(from) || self.is_distinct(to) =>
+            {
+                internals::types::Conversion::Explicit
+            }
+            conversion => conversion,
+        }
+    }
+
+    /// Returns the parameters of the function a callee refers to.
+    fn callee_params(
+        &self,
+        callee: &parser::statements::Expression,
+    ) -> Option<Vec<parser::statements::FuncParam>> {
+        let declaration: &parser::statements::Statement = match callee {
+            parser::statements::Expression::Identifier(name) if self.lookup(name).is_none() => {
+                return self
+                    .functions
+                    .get(name)
+                    .map(|function| function.params.clone());
+            }
+            // A static method -> Point::new(1, 2)
+            parser::statements::Expression::StaticMember { .. }
+                if callee
+                    .path_name()
+                    .is_some_and(|path| self.lookup(&path).is_none()) =>
+            {
+                return self
+                    .functions
+                    .get(&callee.path_name()?)
+                    .map(|function| function.params.clone());
+            }
+            // A function of an imported module -> module.function()
+            parser::statements::Expression::Member { object, member }
+                if matches!(
+                    object.deref(),
+                    parser::statements::Expression::Identifier(module)
+                        if self.imports.contains(module) && self.lookup(module).is_none()
+                ) =>
+            {
+                self.module_declaration(object.path_name()?.as_str(), member)?
+            }
+            parser::statements::Expression::Member { object, member }
+            | parser::statements::Expression::OptionalMember { object, member } => {
+                match self.expression_type(object)?.non_null() {
+                    internals::types::Types::Custom(r#type) => self.method(&r#type, member)?,
+                    _ => {
+                        return None;
+                    }
+                }
+            }
+            // The parameters of a function value don't have names
+            _ => {
+                return match self.expression_type(callee)? {
+                    internals::types::Types::Function(params, _) => Some(
+                        params
+                            .into_iter()
+                            .map(|r#type| parser::statements::FuncParam {
+                                name: String::new(),
+                                r#type,
+                                default: None,
+                                variadic: false,
+                            })
+                            .collect(),
+                    ),
+                    _ => None,
+                };
+            }
+        };
+
+        match declaration {
+            parser::statements::Statement::FunctionDeclaration { params, .. } => {
+                Some(params.clone().unwrap_or_default())
+            }
+            _ => None,
+        }
+    }
+
+    /// Matches the arguments of a call to the parameters of its function, by position first and
+    /// then by name.
+    fn analyze_arguments(
+        &mut self,
+        callee: &parser::statements::Expression,
+        arguments: &[parser::statements::Expression],
+        starts: &[internals::Position],
+        start: &internals::Position,
+    ) {
+        let name: String = callee.path_name().unwrap_or_else(|| "function".to_string());
+
+        let params: Vec<parser::statements::FuncParam> = match self.callee_params(callee) {
+            Some(params) => params,
+            None => {
+                // Only declared functions know the names of their parameters
+                if let Some(parser::statements::Expression::NamedArgument { start, .. }) =
+                    arguments.iter().find(|argument| {
+                        matches!(
+                            argument,
+                            parser::statements::Expression::NamedArgument { .. }
+                        )
+                    })
+                    && matches!(callee, parser::statements::Expression::Identifier(name)
+                        if evaluator::BUILTINS.contains(&name.as_str()))
+                {
+                    self.error(
+                        format!("\"{}\" -> Doesn't take named arguments", name),
+                        start,
+                    );
+                }
+
+                return;
+            }
+        };
+
+        // The parameters of a function value don't have names
+        if params.iter().all(|param| param.name.is_empty())
+            && let Some(parser::statements::Expression::NamedArgument { start, .. }) =
+                arguments.iter().find(|argument| {
+                    matches!(
+                        argument,
+                        parser::statements::Expression::NamedArgument { .. }
+                    )
+                })
+        {
+            self.error(
+                format!(
+                    "\"{}\" -> Function values only take positional arguments",
+                    name
+                ),
+                start,
+            );
+            return;
+        }
+
+        let positional: Vec<(&parser::statements::Expression, &internals::Position)> = arguments
+            .iter()
+            .enumerate()
+            .filter(|(_, argument)| {
+                !matches!(
+                    argument,
+                    parser::statements::Expression::NamedArgument { .. }
+                )
+            })
+            .map(|(index, argument)| (argument, starts.get(index).unwrap_or(start)))
+            .collect();
+
+        // The variadic parameter takes the arguments left after the others
+        let variadic: Option<internals::types::Types> = match params.last() {
+            Some(parser::statements::FuncParam {
+                variadic: true,
+                r#type: internals::types::Types::Slice(element),
+                ..
+            }) => Some(element.deref().clone()),
+            _ => None,
+        };
+        let fixed: usize = params.len() - usize::from(variadic.is_some());
+
+        // Without defaults, names or spreads, the arguments are only counted
+        let counted: bool = positional.len() == arguments.len()
+            && !positional.iter().any(|(argument, _)| {
+                matches!(argument, parser::statements::Expression::Spread { .. })
+            })
+            && params[..fixed].iter().all(|param| param.default.is_none());
+
+        if (variadic.is_none() && positional.len() > params.len())
+            || (counted && variadic.is_none() && positional.len() != params.len())
+        {
+            self.error(
+                format!(
+                    "\"{}\" -> Expected {} arguments but found {}",
+                    name,
+                    params.len(),
+                    arguments.len()
+                ),
+                start,
+            );
+            return;
+        }
+
+        if counted && positional.len() < fixed {
+            self.error(
+                format!(
+                    "\"{}\" -> Expected at least {} arguments but found {}",
+                    name,
+                    fixed,
+                    arguments.len()
+                ),
+                start,
+            );
+            return;
+        }
+
+        let mut given: Vec<bool> = vec![false; params.len()];
+
+        for (index, (argument, argument_start)) in positional.into_iter().enumerate() {
+            let (spread_start, value) = match argument {
+                parser::statements::Expression::Spread { start, value } => {
+                    (Some(start), value.deref())
+                }
+                _ => (None, argument),
+            };
+
+            let element: &internals::types::Types = match (&variadic, spread_start) {
+                (Some(element), _) if index >= fixed => element,
+                (_, Some(spread_start)) => {
+                    self.error(
+                        format!(
+                            "\"{}\" -> Only a variadic parameter takes a spread argument",
+                            name
+                        ),
+                        spread_start,
+                    );
+                    return;
+                }
+                (_, None) => {
+                    given[index] = true;
+                    self.check_conversion(&params[index].r#type, argument, argument_start);
+                    continue;
+                }
+            };
+
+            given[fixed] = true;
+
+            match (spread_start, self.expression_type(value)) {
+                (None, _) => self.check_conversion(element, value, argument_start),
+                (
+                    Some(spread_start),
+                    Some(
+                        internals::types::Types::Array(found, _)
+                        | internals::types::Types::Slice(found),
+                    ),
+                ) => self.check_type_conversion(
+                    element,
+                    &found,
+                    value.is_number_literal(),
+                    spread_start,
+                ),
+                (Some(spread_start), Some(found)) if found.is_known() => self.error(
+                    format!("\"{}\" -> Can't spread {}, only arrays", name, found),
+                    spread_start,
+                ),
+                _ => {}
+            }
+        }
+
+        for argument in arguments {
+            let (argument_start, argument, value) = match argument {
+                parser::statements::Expression::NamedArgument { start, name, value } => {
+                    (start, name, value)
+                }
+                _ => continue,
+            };
+
+            match params.iter().position(|param| param.name == *argument) {
+                Some(index) if given[index] => self.error(
+                    format!(
+                        "\"{}\" -> Parameter \"{}\" is given more than once",
+                        name, argument
+                    ),
+                    argument_start,
+                ),
+                Some(index) => {
+                    given[index] = true;
+                    self.check_conversion(&params[index].r#type, value, argument_start);
+                }
+                None => self.error(
+                    format!("\"{}\" -> Unknown parameter \"{}\"", name, argument),
+                    argument_start,
+                ),
+            }
+        }
+
+        for (param, given) in params.iter().zip(given) {
+            if !given && param.default.is_none() && !param.variadic {
+                self.error(
+                    format!(
+                        "\"{}\" -> Missing argument for parameter \"{}\"",
+                        name, param.name
+                    ),
+                    start,
+                );
+            }
+        }
+    }
+
+    /// Checks that the member of an instance exists and is accessible from here, optional
+    /// members are reached with "?.".
+    fn analyze_member(
+        &mut self,
+        object: &parser::statements::Expression,
+        member: &str,
+        optional: bool,
+        start: &internals::Position,
+    ) {
+        let r#type: internals::types::Types = match self.expression_type(object) {
+            Some(r#type) => r#type,
+            None => {
+                return;
+            }
+        };
+
+        // Only "?." reaches the members of a value that can be null
+        if r#type.is_nullable() && !optional {
+            self.error(
+                format!(
+                    "\"{}\" -> {} can be null, check that it isn't null first or use \"?.\"",
+                    member, r#type
+                ),
+                start,
+            );
+            return;
+        }
+
+        let r#type: String = match r#type.non_null() {
+            internals::types::Types::Custom(r#type) if self.types.contains_key(&r#type) => r#type,
+            // The elements of a tuple are read by their position -> pair.0
+            internals::types::Types::Tuple(types) => {
+                match member.parse::<usize>() {
+                    Ok(index) if index < types.len() => {}
+                    Ok(_) => self.error(
+                        format!(
+                            "\"{}\" -> Out of the range of {}",
+                            member,
+                            internals::types::Types::Tuple(types)
+                        ),
+                        start,
+                    ),
+                    Err(_) => self.error(
+                        format!(
+                            "\"{}\" -> Undefined member of {}",
+                            member,
+                            internals::types::Types::Tuple(types)
+                        ),
+                        start,
+                    ),
+                }
+                return;
+            }
+            // An error only has its message
+            internals::types::Types::Error if member != "message" => {
+                self.error(
+                    format!("\"{}\" -> Undefined member of error", member),
+                    start,
+                );
+                return;
+            }
+            _ => {
+                return;
+            }
+        };
+
+        let visibility: parser::statements::Visibility =
+            match (self.field(&r#type, member), self.method(&r#type, member)) {
+                (Some(field), _) => field.visibility.clone(),
+                (
+                    None,
+                    Some(parser::statements::Statement::FunctionDeclaration { visibility, .. }),
+                ) => visibility.clone(),
+                _ if self.static_member(&r#type, member).is_some() => {
+                    self.error(
+                        format!(
+                            "\"{}\" -> Static member of {}, use {}::{}",
+                            member, r#type, r#type, member
+                        ),
+                        start,
+                    );
+                    return;
+                }
+                _ => {
+                    self.error(
+                        format!("\"{}\" -> Undefined member of {}", member, r#type),
+                        start,
+                    );
+                    return;
+                }
+            };
+
+        // The methods of a type can use all of its members
+        if self.self_type.as_ref() == Some(&r#type) {
+            return;
+        }
+
+        match visibility {
+            parser::statements::Visibility::Public => {}
+            parser::statements::Visibility::Private => self.error(
+                format!("\"{}\" -> Private member of {}", member, r#type),
+                start,
+            ),
+            parser::statements::Visibility::Protected => self.error(
+                format!("\"{}\" -> Protected member of {}", member, r#type),
+                start,
+            ),
+        }
+    }
+
+    /// Checks the fields of a struct literal against its type.
+    fn analyze_struct_literal(
+        &mut self,
+        name: &mut String,
+        fields: &[(String, parser::statements::Expression)],
+        start: &internals::Position,
+    ) {
+        if name == "Self" {
+            match self.self_type.clone() {
+                Some(self_type) => *name = self_type,
+                None => {
+                    self.error("\"Self\" -> Outside of a type".to_string(), start);
+                    return;
+                }
+            }
+        }
+
+        // A generic type takes the type arguments of its field values -> Box { value: 1 }
+        if self.type_templates.contains_key(name.as_str()) {
+            match self.struct_literal_arguments(name, fields) {
+                Some(arguments) => {
+                    if let Some(instance) = self.instantiate_type(name, arguments, start) {
+                        *name = instance;
+                    }
+                }
+                None => {
+                    self.error(
+                        format!("\"{}\" -> Can't infer the type arguments", name),
+                        start,
+                    );
+                    return;
+                }
+            }
+        }
+
+        let declared: Vec<parser::statements::Field> = match self.types.get(name.as_str()) {
+            Some(parser::statements::Statement::TypeDeclaration { fields, .. }) => fields.clone(),
+            _ => {
+                self.error(format!("\"{}\" -> Unknown type", name), start);
+                return;
+            }
+        };
+
+        for (field, _) in fields {
+            if !declared.iter().any(|declared| declared.name == *field) {
+                self.error(
+                    format!("\"{}\" -> Undefined field of {}", field, name),
+                    start,
+                );
+            }
+        }
+
+        for field in declared.iter() {
+            if !fields.iter().any(|(name, _)| *name == field.name) {
+                self.error(
+                    format!("\"{}\" -> Missing field \"{}\"", name, field.name),
+                    start,
+                );
+            }
+        }
+    }
+
+    /// Returns a field of a struct or class.
+    fn field(&self, r#type: &str, name: &str) -> Option<&parser::statements::Field> {
+        match self.types.get(r#type) {
+            Some(parser::statements::Statement::TypeDeclaration { fields, .. }) => {
+                fields.iter().find(|field| field.name == name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a static field, an associated constant or a static method of a struct or class.
+    fn static_member(&self, r#type: &str, name: &str) -> Option<&parser::statements::Statement> {
+        let path: String = format!("{}::{}", r#type, name);
+
+        match self.types.get(r#type) {
+            Some(parser::statements::Statement::TypeDeclaration { statics, .. }) => {
+                statics.iter().find(|member| match member {
+                    parser::statements::Statement::VariableDeclaration { name, .. }
+                    | parser::statements::Statement::ConstantDeclaration { name, .. }
+                    | parser::statements::Statement::FunctionDeclaration { name, .. } => {
+                        *name == path
+                    }
+                    _ => false,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks that a member reached through its type exists, is static and is accessible from
+    /// here -> Point::origin
+    fn analyze_static_member(
+        &mut self,
+        object: &mut parser::statements::Expression,
+        member: &str,
+        start: &internals::Position,
+    ) {
+        let name: &mut String = match object {
+            parser::statements::Expression::Identifier(name) => name,
+            _ => {
+                self.error(
+                    format!("\"{}\" -> Expected a type before \"::\"", member),
+                    start,
+                );
+                return;
+            }
+        };
+
+        // Self and the aliases of the type name the type they stand for
+        let r#type: String = match self
+            .resolve_type(&internals::types::Types::Custom(name.clone()), start)
+            .unaliased()
+        {
+            internals::types::Types::Custom(r#type) if self.types.contains_key(&r#type) => r#type,
+            // An unknown type was reported while resolving it
+            internals::types::Types::Custom(r#type) if !self.aliases.contains_key(&r#type) => {
+                return;
+            }
+            internals::types::Types::Unknown => {
+                return;
+            }
+            r#type => {
+                self.error(
+                    format!("\"{}\" -> {} doesn't have static members", member, r#type),
+                    start,
+                );
+                return;
+            }
+        };
+        *name = r#type.clone();
+
+        let visibility: parser::statements::Visibility = match self.static_member(&r#type, member) {
+            Some(
+                parser::statements::Statement::VariableDeclaration { visibility, .. }
+                | parser::statements::Statement::ConstantDeclaration { visibility, .. }
+                | parser::statements::Statement::FunctionDeclaration { visibility, .. },
+            ) => visibility.clone(),
+            _ => {
+                if self.method(&r#type, member).is_some() {
+                    self.error(
+                        format!(
+                            "\"{}\" -> Instance method of {}, call it on an instance",
+                            member, r#type
+                        ),
+                        start,
+                    );
+                } else if self.field(&r#type, member).is_some() {
+                    self.error(
+                        format!(
+                            "\"{}\" -> Instance field of {}, read it from an instance",
+                            member, r#type
+                        ),
+                        start,
+                    );
+                } else {
+                    self.error(
+                        format!("\"{}\" -> Undefined static member of {}", member, r#type),
+                        start,
+                    );
+                }
+                return;
+            }
+        };
+
+        // The methods of a type can use all of its members
+        if self.self_type.as_ref() == Some(&r#type) {
+            return;
+        }
+
+        match visibility {
+            parser::statements::Visibility::Public => {}
+            parser::statements::Visibility::Private => self.error(
+                format!("\"{}\" -> Private member of {}", member, r#type),
+                start,
+            ),
+            parser::statements::Visibility::Protected => self.error(
+                format!("\"{}\" -> Protected member of {}", member, r#type),
+                start,
+            ),
+        }
+    }
+
+    /// Returns a method of a struct or class.
+    fn method(&self, r#type: &str, name: &str) -> Option<&parser::statements::Statement> {
+        match self.types.get(r#type) {
+            Some(parser::statements::Statement::TypeDeclaration { methods, .. }) => {
+                methods.iter().find(|method| {
+                    matches!(
+                        method,
+                        parser::statements::Statement::FunctionDeclaration { name: method, .. }
+                            if method == name
+                    )
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the top level declaration of an item in a module.
+    fn module_declaration(
+        &self,
+        module: &str,
+        item: &str,
+    ) -> Option<&parser::statements::Statement> {
+        let body = match self.sources.get(module) {
+            Some(parser::statements::Statement::Program { body, .. }) => body,
+            _ => {
+                return None;
+            }
+        };
+
+        body.iter().find(|statement| match statement {
+            parser::statements::Statement::VariableDeclaration { name, .. }
+            | parser::statements::Statement::ConstantDeclaration { name, .. }
+            | parser::statements::Statement::FunctionDeclaration { name, .. } => name == item,
+            _ => false,
+        })
+    }
+
+    /// Checks that an item accessed through an imported module exists and is public.
+    fn analyze_module_item(&mut self, module: &str, item: &str, start: &internals::Position) {
+        // Built-in modules don't have a source to check
+        if !self.sources.contains_key(module) {
+            return;
+        }
+
+        let visibility: Option<parser::statements::Visibility> =
+            match self.module_declaration(module, item) {
+                Some(
+                    parser::statements::Statement::VariableDeclaration { visibility, .. }
+                    | parser::statements::Statement::ConstantDeclaration { visibility, .. }
+                    | parser::statements::Statement::FunctionDeclaration { visibility, .. },
+                ) => Some(visibility.clone()),
+                _ => None,
+            };
+
+        match visibility {
+            Some(parser::statements::Visibility::Public) => {}
+            Some(parser::statements::Visibility::Private) => self.error(
+                format!(
+                    "\"{}.{}\" -> Private item of module \"{}\"",
+                    module, item, module
+                ),
+                start,
+            ),
+            Some(parser::statements::Visibility::Protected) => self.error(
+                format!(
+                    "\"{}.{}\" -> Protected item of module \"{}\"",
+                    module, item, module
+                ),
+                start,
+            ),
+            None => self.error(
+                format!(
+                    "\"{}.{}\" -> Undefined item of module \"{}\"",
+                    module, item, module
+                ),
+                start,
+            ),
+        }
+    }
+
+    /// Checks constant indexes against the size of fixed size arrays.
+    fn analyze_array_acess(
+        &mut self,
+        acess: &mut parser::statements::ArrayAcess,
+        start: &internals::Position,
+    ) {
+        let (array_type, index) = match acess {
+            parser::statements::ArrayAcess::Acess { array, index } => {
+                self.analyze_expression(array, start);
+                (self.expression_type(array), index)
+            }
+            parser::statements::ArrayAcess::NestedAcess { acess, index } => {
+                self.analyze_array_acess(acess, start);
+                (self.array_acess_type(acess), index)
+            }
+        };
+
+        self.analyze_expression(index, start);
+
+        // An instance of a type that overloads "[]" -> grid[2]
+        if let Some(internals::types::Types::Custom(r#type)) = &array_type
+            && self.types.contains_key(r#type)
+        {
+            self.analyze_overload_call(r#type, "[]", index, start);
+            return;
+        }
+
+        if let Some(internals::types::Types::Array(_, size)) = array_type
+            && let Some(index) = self.fold_integer(index)
+            && (index < 0 || index as usize >= size)
+        {
+            self.error(
+                format!(
+                    "Index {} is out of bounds for an array of length {}",
+                    index, size
+                ),
+                start,
+            );
+        }
+    }
+
+    /// Returns the type of an expression when it can be known before running it, with the types
+    /// its aliases name.
+    fn expression_type(
+        &self,
+        expression: &parser::statements::Expression,
+    ) -> Option<internals::types::Types> {
+        self.written_type(expression)
+            .map(|r#type| r#type.unaliased())
+    }
+
+    /// Returns the type of an expression as it's written in its declarations, aliases included.
+    fn written_type(
+        &self,
+        expression: &parser::statements::Expression,
+    ) -> Option<internals::types::Types> {
+        match expression {
+            parser::statements::Expression::Identifier(name) => match self.lookup(name) {
+                Some(symbol) => Some(symbol.r#type.clone()),
+                // A named function used as a value
+                None => self.functions.get(name).map(|function| {
+                    internals::types::Types::Function(
+                        function
+                            .params
+                            .iter()
+                            .map(|param| param.r#type.clone())
+                            .collect(),
+                        Box::new(function.r#type.clone()),
+                    )
+                }),
+            },
+            parser::statements::Expression::ArrayAcess(acess) => self.array_acess_type(acess),
+            parser::statements::Expression::Literal { r#type, value } => match r#type {
+                lexer::tokens::TokenKind::Number if value.contains(['.', 'e', 'E']) => {
+                    Some(internals::types::Types::Double)
+                }
+                // Too big for an int -> 18446744073709551615
+                lexer::tokens::TokenKind::Number if value.parse::<i64>().is_err() => Some(
+                    internals::types::Types::Sized(internals::types::IntKind::U64),
+                ),
+                lexer::tokens::TokenKind::Number => Some(internals::types::Types::Int),
+                lexer::tokens::TokenKind::CharLiteral => Some(internals::types::Types::Char),
+                lexer::tokens::TokenKind::StringLiteral => Some(internals::types::Types::String),
+                lexer::tokens::TokenKind::TyBool => Some(internals::types::Types::Bool),
+                lexer::tokens::TokenKind::Null => Some(internals::types::Types::Null),
+                _ => None,
+            },
+            parser::statements::Expression::Unary { operator, operand } => match operator {
+                lexer::tokens::TokenKind::OpNot => Some(internals::types::Types::Bool),
+                _ => self.expression_type(operand),
+            },
+            parser::statements::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let (left, right) = (self.expression_type(left)?, self.expression_type(right)?);
+
+                // An overloaded operator gives what its method returns
+                if let internals::types::Types::Custom(name) = &left
+                    && let Some(overload) = operator.overload()
+                    && let Some(parser::statements::Statement::FunctionDeclaration {
+                        r#type, ..
+                    }) = self.method(name, overload)
+                {
+                    return Some(r#type.clone());
+                }
+
+                match (operator, &left, &right) {
+                    (
+                        lexer::tokens::TokenKind::OpAdd,
+                        internals::types::Types::String,
+                        internals::types::Types::String,
+                    ) => Some(internals::types::Types::String),
+                    (operator, left, right)
+                        if operator.is_bitwise() && left.is_integer() && right.is_integer() =>
+                    {
+                        left.common_numeric(right)
+                    }
+                    (operator, _, _) if operator.is_bitwise() => None,
+                    _ => left.common_numeric(&right),
+                }
+            }
+            // The type of the left side without its null, unless the fallback can be null too
+            parser::statements::Expression::Logical {
+                operator: lexer::tokens::TokenKind::OpCoalesce,
+                left,
+                right,
+            } => {
+                let left: internals::types::Types = self.expression_type(left)?.non_null();
+
+                if self.expression_type(right)?.is_nullable() {
+                    Some(left.optional())
+                } else {
+                    Some(left)
+                }
+            }
+            parser::statements::Expression::Logical { .. } => Some(internals::types::Types::Bool),
+            parser::statements::Expression::ArrayLiteral { elements } => {
+                let elements = elements.as_deref()?;
+                let mut element: internals::types::Types =
+                    self.expression_type(elements.first()?)?;
+
+                // Mixed numbers are widened to the largest of them
+                for other in elements.iter().skip(1) {
+                    let other: internals::types::Types = self.expression_type(other)?;
+
+                    if other != element {
+                        element = element.common_numeric(&other)?;
+                    }
+                }
+
+                Some(internals::types::Types::Array(
+                    Box::new(element),
+                    elements.len(),
+                ))
+            }
+            parser::statements::Expression::Member { object, member } => {
+                match self.module_item_type(object, member) {
+                    Some(r#type) => Some(r#type),
+                    None => match self.expression_type(object)? {
+                        internals::types::Types::Custom(r#type) => self
+                            .field(&r#type, member)
+                            .map(|field| field.r#type.clone()),
+                        internals::types::Types::Error if member == "message" => {
+                            Some(internals::types::Types::String)
+                        }
+                        internals::types::Types::Tuple(types) => {
+                            types.get(member.parse::<usize>().ok()?).cloned()
+                        }
+                        _ => None,
+                    },
+                }
+            }
+            // Static members are globals named after their type -> Point::origin
+            parser::statements::Expression::StaticMember { .. } => self.written_type(
+                &parser::statements::Expression::Identifier(expression.path_name()?),
+            ),
+            // Both bounds meet as one integer type -> 0..n is a range of u8 for a u8 n
+            parser::statements::Expression::Range { from, to, .. } => {
+                let from: internals::types::Types = self.expression_type(from)?;
+                let to: internals::types::Types = self.expression_type(to)?;
+
+                let item: internals::types::Types = if from == to {
+                    from
+                } else {
+                    from.common_numeric(&to)?
+                };
+
+                Some(internals::types::Types::Range(Box::new(item)))
+            }
+            parser::statements::Expression::Tuple { elements } => {
+                let mut types: Vec<internals::types::Types> = Vec::new();
+
+                for element in elements {
+                    types.push(self.expression_type(element)?);
+                }
+
+                Some(internals::types::Types::Tuple(types))
+            }
+            // The field can be null when the object is -> object?.field
+            parser::statements::Expression::OptionalMember { object, member } => {
+                match self.expression_type(object)?.non_null() {
+                    internals::types::Types::Custom(r#type) => self
+                        .field(&r#type, member)
+                        .map(|field| field.r#type.optional()),
+                    _ => None,
+                }
+            }
+            parser::statements::Expression::Call {
+                callee, arguments, ..
+            } => match callee.deref() {
+                parser::statements::Expression::Identifier(name) if self.lookup(name).is_none() => {
+                    match self.functions.get(name) {
+                        Some(function) => Some(function.r#type.clone()),
+                        None if self.function_templates.contains_key(name) => {
+                            self.generic_call_type(name, arguments.as_deref())
+                        }
+                        None if evaluator::BUILTINS.contains(&name.as_str()) => {
+                            Some(internals::types::Types::Void)
+                        }
+                        None => None,
+                    }
+                }
+                parser::statements::Expression::Member { object, member }
+                | parser::statements::Expression::OptionalMember { object, member } => {
+                    let r#type: internals::types::Types = match self
+                        .module_item_type(object, member)
+                    {
+                        Some(r#type) => Some(r#type),
+                        None => match (self.expression_type(object)?.non_null(), member.as_str()) {
+                            // Methods of the built-in types
+                            (
+                                internals::types::Types::String
+                                | internals::types::Types::Array(..)
+                                | internals::types::Types::Slice(_),
+                                "len",
+                            ) => Some(internals::types::Types::Int),
+                            (r#type, "wrapping_add" | "wrapping_sub" | "wrapping_mul")
+                                if r#type.is_integer() =>
+                            {
+                                Some(r#type)
+                            }
+                            (internals::types::Types::Custom(r#type), method) => {
+                                match (self.method(&r#type, method), self.field(&r#type, method)) {
+                                    (
+                                        Some(parser::statements::Statement::FunctionDeclaration {
+                                            r#type,
+                                            ..
+                                        }),
+                                        _,
+                                    ) => Some(r#type.clone()),
+                                    // A field holding a function
+                                    (
+                                        _,
+                                        Some(parser::statements::Field {
+                                            r#type: internals::types::Types::Function(_, r#type),
+                                            ..
+                                        }),
+                                    ) => Some(r#type.deref().clone()),
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        },
+                    }?;
+
+                    // Nothing is called when the object is null -> object?.method()
+                    match callee.deref() {
+                        parser::statements::Expression::OptionalMember { .. } => {
+                            Some(r#type.optional())
+                        }
+                        _ => Some(r#type),
+                    }
+                }
+                // A value holding a function -> callback(1)
+                _ => match self.expression_type(callee)? {
+                    internals::types::Types::Function(_, r#type) => Some(*r#type),
+                    _ => None,
+                },
+            },
+            parser::statements::Expression::StructLiteral { name, fields } => match name.as_str() {
+                "Self" => self.self_type.clone().map(internals::types::Types::Custom),
+                _ if self.type_templates.contains_key(name) => {
+                    let arguments: Vec<internals::types::Types> =
+                        self.struct_literal_arguments(name, fields)?;
+
+                    Some(internals::types::Types::Custom(generics::instance_name(
+                        name, &arguments,
+                    )))
+                }
+                _ => Some(internals::types::Types::Custom(name.clone())),
+            },
+            parser::statements::Expression::Cast { r#type, .. } => Some(r#type.clone()),
+            parser::statements::Expression::Propagate { value, .. } => {
+                Some(self.expression_type(value)?.success())
+            }
+            parser::statements::Expression::NamedArgument { value, .. } => {
+                self.expression_type(value)
+            }
+            parser::statements::Expression::Block { r#type, .. }
+            | parser::statements::Expression::Loop { r#type, .. } => Some(r#type.clone()),
+            parser::statements::Expression::If {
+                body, alternate, ..
+            } => {
+                let left: internals::types::Types = self.expression_type(body)?;
+                let right: internals::types::Types = self.expression_type(alternate.as_deref()?)?;
+
+                if left == right {
+                    Some(left)
+                } else {
+                    left.common_numeric(&right)
+                }
+            }
+            parser::statements::Expression::Lambda { params, r#type, .. } => {
+                Some(internals::types::Types::Function(
+                    params.iter().map(|param| param.r#type.clone()).collect(),
+                    Box::new(r#type.clone()),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the declared type of an item accessed through an imported module.
+    fn module_item_type(
+        &self,
+        object: &parser::statements::Expression,
+        item: &str,
+    ) -> Option<internals::types::Types> {
+        let module: &String = match object {
+            parser::statements::Expression::Identifier(module)
+                if self.imports.contains(module) && self.lookup(module).is_none() =>
+            {
+                module
+            }
+            _ => {
+                return None;
+            }
+        };
+
+        match self.module_declaration(module, item)? {
+            parser::statements::Statement::VariableDeclaration { r#type, .. }
+            | parser::statements::Statement::ConstantDeclaration { r#type, .. }
+            | parser::statements::Statement::FunctionDeclaration { r#type, .. } => {
+                Some(r#type.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the element an array access refers to.
+    fn array_acess_type(
+        &self,
+        acess: &parser::statements::ArrayAcess,
+    ) -> Option<internals::types::Types> {
+        let array_type: Option<internals::types::Types> = match acess {
+            parser::statements::ArrayAcess::Acess { array, .. } => self.expression_type(array),
+            parser::statements::ArrayAcess::NestedAcess { acess, .. } => {
+                self.array_acess_type(acess)
+            }
+        };
+
+        match array_type? {
+            internals::types::Types::Array(element, _)
+            | internals::types::Types::Slice(element) => Some(*element),
+            internals::types::Types::Custom(r#type) => match self.method(&r#type, "[]")? {
+                parser::statements::Statement::FunctionDeclaration { r#type, .. } => {
+                    Some(r#type.clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Computes the value of an integer expression made only of literals and constants.
+    fn fold_integer(&self, expression: &parser::statements::Expression) -> Option<i64> {
+        match expression {
+            parser::statements::Expression::Literal {
+                r#type: lexer::tokens::TokenKind::Number,
+                value,
+            } => value.parse::<i64>().ok(),
+            parser::statements::Expression::Identifier(name) => self.lookup(name)?.value,
+            parser::statements::Expression::Unary {
+                operator: lexer::tokens::TokenKind::OpSub,
+                operand,
+            } => self.fold_integer(operand)?.checked_neg(),
+            parser::statements::Expression::Unary {
+                operator: lexer::tokens::TokenKind::OpBitNot,
+                operand,
+            } => Some(!self.fold_integer(operand)?),
+            parser::statements::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let (left, right) = (self.fold_integer(left)?, self.fold_integer(right)?);
+
+                match operator {
+                    lexer::tokens::TokenKind::OpAdd => left.checked_add(right),
+                    lexer::tokens::TokenKind::OpSub => left.checked_sub(right),
+                    lexer::tokens::TokenKind::OpMul => left.checked_mul(right),
+                    lexer::tokens::TokenKind::OpDiv => left.checked_div(right),
+                    lexer::tokens::TokenKind::OpMod => left.checked_rem(right),
+                    lexer::tokens::TokenKind::OpBitAnd => Some(left & right),
+                    lexer::tokens::TokenKind::OpBitOr => Some(left | right),
+                    lexer::tokens::TokenKind::OpBitXor => Some(left ^ right),
+                    // Shifts by a negative amount or by the whole width aren't folded
+                    lexer::tokens::TokenKind::OpShl => left.checked_shl(u32::try_from(right).ok()?),
+                    lexer::tokens::TokenKind::OpShr => left.checked_shr(u32::try_from(right).ok()?),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A scope of variables, linked to the scope that encloses it.
+#[derive(Debug, Default)]
+pub struct Environment {
+    values: HashMap<String, evaluator::values::Value>,
+    parent: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new(parent: Option<Rc<RefCell<Environment>>>) -> Self {
+        Self {
+            values: HashMap::new(),
+            parent,
+        }
+    }
+
+    /// Declares a variable in this scope, shadowing any outer one.
+    pub fn define(&mut self, name: String, value: evaluator::values::Value) {
+        self.values.insert(name, value);
+    }
+
+    /// Looks a variable up through this scope and its parents.
+    pub fn get(&self, name: &str) -> Option<evaluator::values::Value> {
+        match self.values.get(name) {
+            Some(value) => Some(value.clone()),
+            None => match &self.parent {
+                Some(parent) => parent.borrow().get(name),
+                None => None,
+            },
+        }
+    }
+
+    /// Changes the value of an existing variable, returns false if it doesn't exist.
+    pub fn assign(&mut self, name: &str, value: evaluator::values::Value) -> bool {
+        if let Some(slot) = self.values.get_mut(name) {
+            *slot = value;
+            return true;
+        }
+
+        match &self.parent {
+            Some(parent) => parent.borrow_mut().assign(name, value),
+            None => false,
+        }
+    }
+}
@@ -1,10 +1,88 @@
+pub mod environment;
+pub mod values;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ops::Deref;
+use std::rc::Rc;
 
 use crate::*;
 
+/// The deepest nesting of function calls before the evaluator gives up.
+const MAX_CALL_DEPTH: usize = 512;
+
+/// The stack an evaluation runs on, each call nests several evaluator frames so the default stack
+/// of a thread overflows long before MAX_CALL_DEPTH in a debug build.
+pub const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// The functions provided by the evaluator itself.
+pub const BUILTINS: &[&str] = &[
+    "print",
+    "println",
+    "std.console.print",
+    "std.console.printl",
+];
+
+/// The modules provided by the evaluator itself, they don't have a source file.
+pub const BUILTIN_MODULES: &[&str] = &["stdio"];
+
+/// How the execution of a statement ended.
+enum Flow {
+    Normal,
+    /// Leaves the innermost loop or the one with the label, a loop used as a value takes the value
+    Break {
+        label: Option<String>,
+        value: values::Value,
+    },
+    /// Goes to the next iteration of the innermost loop or of the one with the label
+    Continue(Option<String>),
+    Return(values::Value),
+    Throw(values::Failure),
+    Abort,
+}
+
+/// The evaluated arguments of a call, the named ones are matched to the parameters by name.
+#[derive(Default)]
+struct Arguments {
+    positional: Vec<values::Value>,
+    named: Vec<(String, values::Value)>,
+}
+
+/// What is left to iterate in a for loop -> for item in items
+enum Items {
+    Range {
+        next: i128,
+        to: i128,
+        inclusive: bool,
+        item: internals::types::Types,
+    },
+    Values(std::vec::IntoIter<values::Value>),
+    /// An instance whose next() gives the items until it gives null
+    Iterator(values::Value),
+}
+
+/// The evaluated top level declarations of a module.
+struct Module {
+    globals: Rc<RefCell<environment::Environment>>,
+    functions: HashMap<String, parser::statements::Statement>,
+    types: HashMap<String, parser::statements::Statement>,
+    imports: Vec<String>,
+}
+
 pub struct Evaluator {
     parser_output: parser::statements::Statement,
-    output: Vec<handling::Message>,
+    pub output: Vec<handling::Message>,
+    sources: HashMap<String, parser::statements::Statement>,
+    modules: HashMap<String, Module>,
+    // The module the running code belongs to, the evaluated program is ""
+    module: String,
+    environment: Rc<RefCell<environment::Environment>>,
+    position: Option<internals::Position>,
+    depth: usize,
+    // A jump out of a block expression, carried to the statements around it
+    unwinding: Option<Flow>,
+    // A release build wraps the integers that overflow instead of stopping the program
+    release: bool,
 }
 
 impl Evaluator {
@@ -12,57 +90,2059 @@ impl Evaluator {
         Self {
             parser_output,
             output: Vec::new(),
+            sources: HashMap::new(),
+            modules: HashMap::new(),
+            module: String::new(),
+            environment: Rc::new(RefCell::new(environment::Environment::new(None))),
+            position: None,
+            depth: 0,
+            unwinding: None,
+            release: false,
         }
     }
 
+    /// Runs the program as a release build, integer overflows wrap around.
+    pub fn set_release(&mut self, release: bool) {
+        self.release = release;
+    }
+
+    /// Makes a module available to the "use" statements of the program.
+    pub fn add_module(&mut self, name: String, parser_output: parser::statements::Statement) {
+        self.sources.insert(name, parser_output);
+    }
+
     pub fn evaluate(&mut self) {
         match self.parser_output.clone() {
             parser::statements::Statement::Program { body, .. } => {
-                let unboxed_body = body.deref().clone();
+                self.evaluate_module(String::new(), body.deref());
+            }
+            _ => self.output.push(handling::Message::new(
+                handling::MessageKind::Error,
+                "Invalid AST".to_string(),
+                None,
+            )),
+        }
+
+        if !self.output.is_empty() {
+            return;
+        }
+
+        // The entry point of the program, if it has one
+        if self.current_module().functions.contains_key("main")
+            && let Some(values::Value::Error(failure)) =
+                self.call_function("main", Arguments::default())
+        {
+            self.output.push(handling::Message::new(
+                handling::MessageKind::CodeError,
+                format!("Uncaught error: {}", failure.message),
+                failure.position,
+            ));
+        }
+    }
+
+    /// Evaluates the top level declarations of a module.
+    fn evaluate_module(&mut self, name: String, body: &[parser::statements::Statement]) {
+        let globals = Rc::new(RefCell::new(environment::Environment::new(None)));
+
+        self.modules.insert(
+            name.clone(),
+            Module {
+                globals: globals.clone(),
+                functions: HashMap::new(),
+                types: HashMap::new(),
+                imports: Vec::new(),
+            },
+        );
+
+        let previous_module: String = std::mem::replace(&mut self.module, name);
+        let previous_environment = std::mem::replace(&mut self.environment, globals);
+
+        for statement in body {
+            match statement {
+                parser::statements::Statement::VariableDeclaration { .. }
+                | parser::statements::Statement::Destructure { .. } => {
+                    self.evaluate_variable_statement(statement)
+                }
+                parser::statements::Statement::ConstantDeclaration { .. } => {
+                    self.evaluate_constant_statement(statement)
+                }
+                parser::statements::Statement::FunctionDeclaration { .. } => {
+                    self.evaluate_function_statement(statement)
+                }
+                parser::statements::Statement::TypeDeclaration { .. }
+                | parser::statements::Statement::TypeAlias { .. } => {
+                    self.evaluate_type_statement(statement)
+                }
+                parser::statements::Statement::Use { start, module } => {
+                    self.position = Some(start.clone());
+                    self.evaluate_use_statement(module);
+                }
+                parser::statements::Statement::VariableAlteration { .. } => {
+                    self.execute_statement(statement);
+                }
+                _ => {
+                    self.output.push(handling::Message::new(
+                        handling::MessageKind::Error,
+                        "Invalid AST".to_string(),
+                        None,
+                    ));
+                }
+            }
+
+            if !self.output.is_empty() {
+                break;
+            }
+        }
+
+        self.module = previous_module;
+        self.environment = previous_environment;
+    }
+
+    /// Imports a module into the current one, evaluating it the first time it's used.
+    fn evaluate_use_statement(&mut self, module: &str) {
+        self.current_module_mut().imports.push(module.to_string());
+
+        if BUILTIN_MODULES.contains(&module) || self.modules.contains_key(module) {
+            return;
+        }
+
+        match self.sources.get(module).cloned() {
+            Some(parser::statements::Statement::Program { body, .. }) => {
+                self.evaluate_module(module.to_string(), body.deref())
+            }
+            _ => self.error(format!("\"{}\" -> Unknown module", module)),
+        }
+    }
+
+    /// Registers a function so it can be called later.
+    fn evaluate_function_statement(&mut self, input: &parser::statements::Statement) {
+        if let parser::statements::Statement::FunctionDeclaration {
+            name,
+            body: Some(_),
+            ..
+        } = input
+        {
+            self.current_module_mut()
+                .functions
+                .insert(name.clone(), input.clone());
+        }
+    }
+
+    /// Registers a struct, a class or a type alias so it can be used later, the static members
+    /// of a type are globals named after it -> Point::origin
+    fn evaluate_type_statement(&mut self, input: &parser::statements::Statement) {
+        if let parser::statements::Statement::TypeDeclaration { name, .. }
+        | parser::statements::Statement::TypeAlias { name, .. } = input
+        {
+            self.current_module_mut()
+                .types
+                .insert(name.clone(), input.clone());
+        }
+
+        if let parser::statements::Statement::TypeDeclaration { statics, .. } = input {
+            for member in statics.iter() {
+                match member {
+                    parser::statements::Statement::FunctionDeclaration { .. } => {
+                        self.evaluate_function_statement(member)
+                    }
+                    _ => {
+                        self.execute_statement(member);
+                    }
+                }
+
+                if !self.output.is_empty() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn evaluate_variable_statement(&mut self, input: &parser::statements::Statement) {
+        self.execute_statement(input);
+    }
+
+    fn evaluate_constant_statement(&mut self, input: &parser::statements::Statement) {
+        self.execute_statement(input);
+    }
+
+    fn current_module(&self) -> &Module {
+        &self.modules[&self.module]
+    }
+
+    fn current_module_mut(&mut self) -> &mut Module {
+        self.modules.get_mut(&self.module).unwrap()
+    }
+
+    /// Returns the module and the name of an item accessed through an imported module -> module.item
+    fn module_item(&self, expression: &parser::statements::Expression) -> Option<(String, String)> {
+        let (object, member) = match expression {
+            parser::statements::Expression::Member { object, member } => (object, member),
+            parser::statements::Expression::StaticMember { r#type, member } => (r#type, member),
+            _ => {
+                return None;
+            }
+        };
+
+        match object.deref() {
+            parser::statements::Expression::Identifier(module)
+                if self.current_module().imports.contains(module)
+                    && self.modules.contains_key(module)
+                    && self.environment.borrow().get(module).is_none() =>
+            {
+                Some((module.clone(), member.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The value of a variable declared without an initializer, instances included.
+    fn default_value(&self, r#type: &internals::types::Types) -> values::Value {
+        match r#type {
+            internals::types::Types::Custom(name) => match self.current_module().types.get(name) {
+                Some(parser::statements::Statement::TypeDeclaration { fields, .. }) => {
+                    values::Value::new_object(
+                        self.module.clone(),
+                        name.clone(),
+                        fields
+                            .iter()
+                            .map(|field| (field.name.clone(), self.default_value(&field.r#type)))
+                            .collect(),
+                    )
+                }
+                Some(parser::statements::Statement::TypeAlias { r#type, .. }) => {
+                    self.default_value(r#type)
+                }
+                _ => values::Value::Void,
+            },
+            internals::types::Types::Alias(_, r#type) => self.default_value(r#type),
+            internals::types::Types::Array(element, size) => {
+                values::Value::new_array((0..*size).map(|_| self.default_value(element)).collect())
+            }
+            internals::types::Types::Tuple(types) => values::Value::Tuple(
+                types
+                    .iter()
+                    .map(|r#type| self.default_value(r#type))
+                    .collect(),
+            ),
+            _ => values::Value::default_for(r#type),
+        }
+    }
+
+    /// The type the values of a type are, a distinct type has the values of its underlying one.
+    fn underlying_type(&self, r#type: &internals::types::Types) -> internals::types::Types {
+        match r#type {
+            internals::types::Types::Custom(name) => match self.current_module().types.get(name) {
+                Some(parser::statements::Statement::TypeAlias { r#type, .. }) => {
+                    self.underlying_type(r#type)
+                }
+                _ => r#type.clone(),
+            },
+            _ => r#type.unaliased(),
+        }
+    }
+
+    /// Pushes a runtime error at the position of the statement being executed.
+    fn error(&mut self, message: String) {
+        self.output.push(handling::Message::new(
+            handling::MessageKind::CodeError,
+            message,
+            self.position.clone(),
+        ));
+    }
+
+    /// Executes the statements of a block inside a new scope.
+    fn execute_block(&mut self, block: &[parser::statements::Statement]) -> Flow {
+        let scope = Rc::new(RefCell::new(environment::Environment::new(Some(
+            self.environment.clone(),
+        ))));
+        let previous = std::mem::replace(&mut self.environment, scope);
+
+        let flow: Flow = self.execute_statements(block);
+
+        self.environment = previous;
+        flow
+    }
+
+    /// Executes statements in the current scope until one of them changes the flow, then the
+    /// deferred bodies of the scope from the last one to the first.
+    fn execute_statements(&mut self, statements: &[parser::statements::Statement]) -> Flow {
+        let mut deferred: Vec<&Vec<parser::statements::Statement>> = Vec::new();
+        let mut flow: Flow = Flow::Normal;
+
+        for statement in statements {
+            if let parser::statements::Statement::Defer { body, .. } = statement {
+                deferred.extend(body.as_deref());
+                continue;
+            }
+
+            match self.execute_statement(statement) {
+                Flow::Normal => {}
+                // A return, break or continue inside a block expression -> var x = { return 1; };
+                Flow::Abort => {
+                    flow = self.unwinding.take().unwrap_or(Flow::Abort);
+                    break;
+                }
+                other => {
+                    flow = other;
+                    break;
+                }
+            }
+        }
+
+        // The value of a return is known before the deferred bodies run
+        for body in deferred.into_iter().rev() {
+            if let Flow::Abort = self.execute_block(body) {
+                return Flow::Abort;
+            }
+        }
 
-                for statement in unboxed_body {
-                    match statement.clone() {
-                        parser::statements::Statement::VariableDeclaration { .. } => {
-                            self.evaluate_variable_statement(statement)
+        flow
+    }
+
+    fn execute_statement(&mut self, statement: &parser::statements::Statement) -> Flow {
+        match statement {
+            parser::statements::Statement::VariableDeclaration {
+                start,
+                name,
+                r#type,
+                value,
+                ..
+            } => {
+                self.position = Some(start.clone());
+
+                let value: values::Value = match value {
+                    Some(expression) => match self.evaluate_expression(expression) {
+                        Some(value) => value.coerce(r#type),
+                        None => {
+                            return Flow::Abort;
                         }
-                        parser::statements::Statement::ConstantDeclaration { .. } => {
-                            self.evaluate_constant_statement(statement)
+                    },
+                    None => self.default_value(r#type),
+                };
+
+                self.environment.borrow_mut().define(name.clone(), value);
+                Flow::Normal
+            }
+            parser::statements::Statement::Destructure {
+                start,
+                names,
+                r#type,
+                value,
+                ..
+            } => {
+                self.position = Some(start.clone());
+
+                let elements: Vec<values::Value> = match self.evaluate_expression(value) {
+                    Some(values::Value::Tuple(elements)) if elements.len() == names.len() => {
+                        elements
+                    }
+                    Some(other) => {
+                        self.error(format!(
+                            "Can't split {} into {} variables",
+                            other.get_type(),
+                            names.len()
+                        ));
+                        return Flow::Abort;
+                    }
+                    None => {
+                        return Flow::Abort;
+                    }
+                };
+
+                for (index, (name, element)) in names.iter().zip(elements).enumerate() {
+                    let element: values::Value = match r#type.unaliased() {
+                        internals::types::Types::Tuple(types) => element.coerce(&types[index]),
+                        _ => element,
+                    };
+
+                    self.environment.borrow_mut().define(name.clone(), element);
+                }
+
+                Flow::Normal
+            }
+            parser::statements::Statement::ConstantDeclaration {
+                start,
+                name,
+                r#type,
+                value,
+                ..
+            } => {
+                self.position = Some(start.clone());
+
+                let value: values::Value = match self.evaluate_expression(value) {
+                    Some(value) => value.coerce(r#type),
+                    None => {
+                        return Flow::Abort;
+                    }
+                };
+
+                self.environment.borrow_mut().define(name.clone(), value);
+                Flow::Normal
+            }
+            parser::statements::Statement::If {
+                start,
+                condition,
+                body,
+                alternate,
+            }
+            | parser::statements::Statement::ElseIf {
+                start,
+                condition,
+                body,
+                alternate,
+            } => {
+                self.position = Some(start.clone());
+
+                let branch = match self.evaluate_condition(condition) {
+                    Some(true) => body,
+                    Some(false) => alternate,
+                    None => {
+                        return Flow::Abort;
+                    }
+                };
+
+                match branch {
+                    Some(branch) => self.execute_block(branch),
+                    None => Flow::Normal,
+                }
+            }
+            parser::statements::Statement::Else { body, .. } => match body {
+                Some(body) => self.execute_block(body),
+                None => Flow::Normal,
+            },
+            parser::statements::Statement::While {
+                start,
+                label,
+                condition,
+                body,
+            } => {
+                self.position = Some(start.clone());
+
+                loop {
+                    match self.evaluate_condition(condition) {
+                        Some(true) => {}
+                        Some(false) => break,
+                        None => {
+                            return Flow::Abort;
                         }
-                        parser::statements::Statement::FunctionDeclaration { .. } => {
-                            self.evaluate_function_statement(statement)
+                    }
+
+                    match self.execute_loop_body(label.as_deref(), body.as_deref()) {
+                        Flow::Normal | Flow::Continue(None) => {}
+                        Flow::Break { label: None, .. } => break,
+                        flow => {
+                            return flow;
                         }
-                        parser::statements::Statement::VariableAlteration { .. } => todo!(),
-                        _ => {
-                            self.output.push(handling::Message::new(
-                                handling::MessageKind::Error,
-                                "Invalid AST".to_string(),
-                                None,
-                            ));
-                            break;
+                    }
+                }
+
+                Flow::Normal
+            }
+            parser::statements::Statement::Loop { label, body, .. } => loop {
+                match self.execute_loop_body(label.as_deref(), body.as_deref()) {
+                    Flow::Normal | Flow::Continue(None) => {}
+                    Flow::Break { label: None, .. } => break Flow::Normal,
+                    flow => {
+                        return flow;
+                    }
+                }
+            },
+            parser::statements::Statement::For {
+                start,
+                label,
+                variable,
+                condition,
+                variable_update,
+                body,
+            } => {
+                // The initialization lives in its own scope around the loop
+                let scope = Rc::new(RefCell::new(environment::Environment::new(Some(
+                    self.environment.clone(),
+                ))));
+                let previous = std::mem::replace(&mut self.environment, scope);
+
+                let flow: Flow = self.execute_for(
+                    start,
+                    label.as_deref(),
+                    variable,
+                    condition,
+                    variable_update,
+                    body.as_deref(),
+                );
+
+                self.environment = previous;
+                flow
+            }
+            parser::statements::Statement::ForIn {
+                start,
+                label,
+                name,
+                iterable,
+                body,
+            } => self.execute_for_in(start, label.as_deref(), name, iterable, body.as_deref()),
+            parser::statements::Statement::VariableAlteration {
+                start,
+                target,
+                operator,
+                value,
+            } => {
+                self.position = Some(start.clone());
+
+                let value: values::Value = match self.evaluate_alteration(target, operator, value) {
+                    Some(value) => value,
+                    None => {
+                        return Flow::Abort;
+                    }
+                };
+
+                match self.assign(target, value) {
+                    Some(_) => Flow::Normal,
+                    None => Flow::Abort,
+                }
+            }
+            parser::statements::Statement::Throw { start, value } => {
+                self.position = Some(start.clone());
+
+                match self.evaluate_expression(value) {
+                    // A caught error keeps where it was first thrown
+                    Some(values::Value::Error(failure)) => Flow::Throw(failure),
+                    Some(value) => Flow::Throw(values::Failure {
+                        message: value.to_string(),
+                        position: Some(start.clone()),
+                    }),
+                    None => Flow::Abort,
+                }
+            }
+            parser::statements::Statement::Try {
+                start,
+                body,
+                name,
+                catch,
+            } => {
+                self.position = Some(start.clone());
+
+                let flow: Flow = match body {
+                    Some(body) => self.execute_block(body),
+                    None => Flow::Normal,
+                };
+
+                // Only the errors given by "throw" and "?" are caught, a failure of the program
+                // still stops it
+                let failure: values::Failure = match flow {
+                    Flow::Throw(failure) => failure,
+                    flow => {
+                        return flow;
+                    }
+                };
+
+                let scope = Rc::new(RefCell::new(environment::Environment::new(Some(
+                    self.environment.clone(),
+                ))));
+                let previous = std::mem::replace(&mut self.environment, scope);
+
+                if let Some(name) = name {
+                    self.environment
+                        .borrow_mut()
+                        .define(name.clone(), values::Value::Error(failure));
+                }
+
+                let flow: Flow = match catch {
+                    Some(catch) => self.execute_statements(catch),
+                    None => Flow::Normal,
+                };
+
+                self.environment = previous;
+                flow
+            }
+            parser::statements::Statement::Break {
+                start,
+                label,
+                value,
+            } => {
+                self.position = Some(start.clone());
+
+                let value: values::Value = match value {
+                    Some(value) => match self.evaluate_expression(value) {
+                        Some(value) => value,
+                        None => {
+                            return Flow::Abort;
                         }
+                    },
+                    None => values::Value::Void,
+                };
+
+                Flow::Break {
+                    label: label.clone(),
+                    value,
+                }
+            }
+            parser::statements::Statement::Continue { label, .. } => Flow::Continue(label.clone()),
+            parser::statements::Statement::Return { start, expression } => {
+                self.position = Some(start.clone());
+
+                match expression {
+                    Some(expression) => match self.evaluate_expression(expression) {
+                        Some(value) => Flow::Return(value),
+                        None => Flow::Abort,
+                    },
+                    None => Flow::Return(values::Value::Void),
+                }
+            }
+            parser::statements::Statement::FunctionCall {
+                start,
+                call: expression,
+            }
+            | parser::statements::Statement::Expression { start, expression } => {
+                self.position = Some(start.clone());
+
+                match self.evaluate_expression(expression) {
+                    Some(_) => Flow::Normal,
+                    None => Flow::Abort,
+                }
+            }
+            _ => {
+                self.error("Unsupported statement".to_string());
+                Flow::Abort
+            }
+        }
+    }
+
+    /// Executes one iteration of a loop body.
+    /// The jumps to the label of the loop lose it, so they are handled like the jumps to the
+    /// innermost loop.
+    fn execute_loop_body(
+        &mut self,
+        label: Option<&str>,
+        body: Option<&Vec<parser::statements::Statement>>,
+    ) -> Flow {
+        let flow: Flow = match body {
+            Some(body) => self.execute_block(body),
+            None => Flow::Normal,
+        };
+
+        match flow {
+            Flow::Break {
+                label: Some(target),
+                value,
+            } if label == Some(target.as_str()) => Flow::Break { label: None, value },
+            Flow::Continue(Some(target)) if label == Some(target.as_str()) => Flow::Continue(None),
+            flow => flow,
+        }
+    }
+
+    /// Executes a for loop inside the scope of its initialization.
+    fn execute_for(
+        &mut self,
+        start: &internals::Position,
+        label: Option<&str>,
+        variable: &Option<Box<parser::statements::Statement>>,
+        condition: &Option<parser::statements::Expression>,
+        variable_update: &Option<Box<parser::statements::Statement>>,
+        body: Option<&Vec<parser::statements::Statement>>,
+    ) -> Flow {
+        if let Some(variable) = variable
+            && let Flow::Abort = self.execute_statement(variable)
+        {
+            return Flow::Abort;
+        }
+
+        loop {
+            self.position = Some(start.clone());
+
+            if let Some(condition) = condition {
+                match self.evaluate_condition(condition) {
+                    Some(true) => {}
+                    Some(false) => break,
+                    None => {
+                        return Flow::Abort;
                     }
                 }
             }
-            _ => self.output.push(handling::Message::new(
-                handling::MessageKind::Error,
-                "Invalid AST".to_string(),
-                None,
-            )),
+
+            match self.execute_loop_body(label, body) {
+                Flow::Normal | Flow::Continue(None) => {}
+                Flow::Break { label: None, .. } => break,
+                flow => {
+                    return flow;
+                }
+            }
+
+            if let Some(variable_update) = variable_update
+                && let Flow::Abort = self.execute_statement(variable_update)
+            {
+                return Flow::Abort;
+            }
+        }
+
+        Flow::Normal
+    }
+
+    /// Executes the body of a for loop once for each item of an iterable, in a scope holding the item.
+    fn execute_for_in(
+        &mut self,
+        start: &internals::Position,
+        label: Option<&str>,
+        name: &str,
+        iterable: &parser::statements::Expression,
+        body: Option<&Vec<parser::statements::Statement>>,
+    ) -> Flow {
+        self.position = Some(start.clone());
+
+        let mut items: Items = match self.evaluate_expression(iterable) {
+            Some(values::Value::Range {
+                from,
+                to,
+                inclusive,
+                item,
+            }) => Items::Range {
+                next: from,
+                to,
+                inclusive,
+                item,
+            },
+            Some(values::Value::Array(array)) => Items::Values(array.borrow().clone().into_iter()),
+            Some(values::Value::String(string)) => Items::Values(
+                string
+                    .chars()
+                    .map(values::Value::Char)
+                    .collect::<Vec<values::Value>>()
+                    .into_iter(),
+            ),
+            // A type can give a separate iterator -> items.iter()
+            Some(object @ values::Value::Object(_)) if self.has_method(&object, "iter") => {
+                match self.call_method(object, "iter", Arguments::default()) {
+                    Some(iterator) => Items::Iterator(iterator),
+                    None => {
+                        return Flow::Abort;
+                    }
+                }
+            }
+            Some(object @ values::Value::Object(_)) => Items::Iterator(object),
+            Some(other) => {
+                self.error(format!("Can't iterate over {}", other.get_type()));
+                return Flow::Abort;
+            }
+            None => {
+                return Flow::Abort;
+            }
+        };
+
+        loop {
+            let item: values::Value = match self.next_item(&mut items) {
+                Some(Some(item)) => item,
+                Some(None) => break,
+                None => {
+                    return Flow::Abort;
+                }
+            };
+
+            let scope = Rc::new(RefCell::new(environment::Environment::new(Some(
+                self.environment.clone(),
+            ))));
+            scope.borrow_mut().define(name.to_string(), item);
+            let previous = std::mem::replace(&mut self.environment, scope);
+
+            let flow: Flow = self.execute_loop_body(label, body);
+            self.environment = previous;
+
+            match flow {
+                Flow::Normal | Flow::Continue(None) => {}
+                Flow::Break { label: None, .. } => break,
+                flow => {
+                    return flow;
+                }
+            }
+        }
+
+        Flow::Normal
+    }
+
+    /// Takes the next item of a for loop, None when getting it failed.
+    fn next_item(&mut self, items: &mut Items) -> Option<Option<values::Value>> {
+        match items {
+            Items::Range {
+                next,
+                to,
+                inclusive,
+                item,
+            } => {
+                if *next > *to || (*next == *to && !*inclusive) {
+                    return Some(None);
+                }
+
+                // The bounds are wider than any integer type, the largest one is still an item
+                let value: values::Value = values::Value::new_integer(item, *next);
+                *next += 1;
+
+                Some(Some(value))
+            }
+            Items::Values(values) => Some(values.next()),
+            Items::Iterator(iterator) => {
+                match self.call_method(iterator.clone(), "next", Arguments::default())? {
+                    values::Value::Null => Some(None),
+                    item => Some(Some(item)),
+                }
+            }
+        }
+    }
+
+    /// Computes the new value of an assignment target -> '=', "+=", "++"...
+    fn evaluate_alteration(
+        &mut self,
+        target: &parser::statements::Expression,
+        operator: &lexer::tokens::TokenKind,
+        value: &Option<parser::statements::Expression>,
+    ) -> Option<values::Value> {
+        if *operator == lexer::tokens::TokenKind::OpAssign {
+            return self.evaluate_expression(value.as_ref()?);
         }
+
+        let binary_operator: lexer::tokens::TokenKind = match operator.binary_operator() {
+            Some(binary_operator) => binary_operator,
+            None => {
+                self.error(format!("Invalid assignment operator {:?}", operator));
+                return None;
+            }
+        };
+
+        let current: values::Value = self.evaluate_expression(target)?;
+        let operand: values::Value = match value {
+            Some(value) => self.evaluate_expression(value)?,
+            None => values::Value::Int(1),
+        };
+
+        let result: values::Value =
+            self.evaluate_binary(&binary_operator, current.clone(), operand)?;
+        Some(result.coerce(&current.get_type()))
     }
 
-    fn evaluate_function_statement(&mut self, input: parser::statements::Statement) {
-        println!("Evaluating function:");
-        dbg!(input);
+    /// Stores a value in an assignment target.
+    fn assign(
+        &mut self,
+        target: &parser::statements::Expression,
+        value: values::Value,
+    ) -> Option<()> {
+        match target {
+            parser::statements::Expression::Identifier(name) => {
+                let current: Option<values::Value> = self.environment.borrow().get(name);
+
+                match current {
+                    Some(current) => {
+                        let value: values::Value = value.coerce(&current.get_type());
+                        self.environment.borrow_mut().assign(name, value);
+                        Some(())
+                    }
+                    None => {
+                        self.error(format!("\"{}\" -> Undefined variable", name));
+                        None
+                    }
+                }
+            }
+            parser::statements::Expression::Member { .. } if self.module_item(target).is_some() => {
+                let (module, global) = self.module_item(target)?;
+                let globals = self.modules[&module].globals.clone();
+                let current: Option<values::Value> = globals.borrow().get(&global);
+
+                match current {
+                    Some(current) => {
+                        globals
+                            .borrow_mut()
+                            .assign(&global, value.coerce(&current.get_type()));
+                        Some(())
+                    }
+                    None => {
+                        self.error(format!("\"{}.{}\" -> Undefined variable", module, global));
+                        None
+                    }
+                }
+            }
+            // A static field is a global named after its type -> Counter::count
+            parser::statements::Expression::StaticMember { .. } => self.assign(
+                &parser::statements::Expression::Identifier(target.path_name()?),
+                value,
+            ),
+            parser::statements::Expression::Member { object, member } => {
+                let object: values::Object = self.evaluate_object(object)?;
+                let mut instance = object.borrow_mut();
+
+                match instance.field_mut(member) {
+                    Some(field) => {
+                        let value: values::Value = value.coerce(&field.get_type());
+                        *field = value;
+                        Some(())
+                    }
+                    None => {
+                        let r#type: String = instance.r#type.clone();
+                        drop(instance);
+                        self.error(format!("\"{}\" -> Undefined field of {}", member, r#type));
+                        None
+                    }
+                }
+            }
+            parser::statements::Expression::ArrayAcess(acess) => {
+                let (array, index) = self.evaluate_array_acess(acess)?;
+                let mut elements = array.borrow_mut();
+
+                let value: values::Value = value.coerce(&elements[index].get_type());
+                elements[index] = value;
+                Some(())
+            }
+            _ => {
+                self.error("Invalid assignment target".to_string());
+                None
+            }
+        }
     }
 
-    fn evaluate_variable_statement(&mut self, input: parser::statements::Statement) {
-        println!("Evaluating variable:");
-        dbg!(input);
+    /// The value an access gives, an instance of a type that overloads "[]" gives the result of
+    /// it -> grid[2]
+    fn evaluate_index(&mut self, acess: &parser::statements::ArrayAcess) -> Option<values::Value> {
+        let (container, index) = match acess {
+            parser::statements::ArrayAcess::Acess { array, index } => {
+                (self.evaluate_expression(array)?, index)
+            }
+            parser::statements::ArrayAcess::NestedAcess { acess, index } => {
+                (self.evaluate_index(acess)?, index)
+            }
+        };
+
+        if let values::Value::Object(_) = &container {
+            let index: values::Value = self.evaluate_expression(index)?;
+
+            return self.call_method(
+                container,
+                "[]",
+                Arguments {
+                    positional: vec![index],
+                    ..Default::default()
+                },
+            );
+        }
+
+        let (array, position) = self.checked_position(container, index)?;
+        let element: values::Value = array.borrow()[position].clone();
+        Some(element)
     }
 
-    fn evaluate_constant_statement(&mut self, input: parser::statements::Statement) {
-        println!("Evaluating constant:");
-        dbg!(input);
+    /// Finds the array and the checked position an array access refers to.
+    fn evaluate_array_acess(
+        &mut self,
+        acess: &parser::statements::ArrayAcess,
+    ) -> Option<(values::Array, usize)> {
+        let (array, index) = match acess {
+            parser::statements::ArrayAcess::Acess { array, index } => {
+                (self.evaluate_expression(array)?, index)
+            }
+            parser::statements::ArrayAcess::NestedAcess { acess, index } => {
+                (self.evaluate_index(acess)?, index)
+            }
+        };
+
+        self.checked_position(array, index)
+    }
+
+    /// Checks that a value is an array and that the index is inside it.
+    fn checked_position(
+        &mut self,
+        array: values::Value,
+        index: &parser::statements::Expression,
+    ) -> Option<(values::Array, usize)> {
+        let array: values::Array = match array {
+            values::Value::Array(array) => array,
+            other => {
+                self.error(format!("Can't index a value of type {}", other.get_type()));
+                return None;
+            }
+        };
+
+        let index: i64 = match self.evaluate_expression(index)? {
+            values::Value::Int(index) => index,
+            values::Value::Sized(_, index) => index as i64,
+            other => {
+                self.error(format!(
+                    "Expected an int index but found {}",
+                    other.get_type()
+                ));
+                return None;
+            }
+        };
+
+        let length: usize = array.borrow().len();
+
+        if index < 0 || index as usize >= length {
+            self.error(format!(
+                "Index {} is out of bounds for an array of length {}",
+                index, length
+            ));
+            return None;
+        }
+
+        Some((array, index as usize))
+    }
+
+    /// Evaluates an expression that must result in an instance of a struct or class.
+    fn evaluate_object(
+        &mut self,
+        expression: &parser::statements::Expression,
+    ) -> Option<values::Object> {
+        match self.evaluate_expression(expression)? {
+            values::Value::Object(object) => Some(object),
+            other => {
+                self.error(format!(
+                    "Can't access a member of a value of type {}",
+                    other.get_type()
+                ));
+                None
+            }
+        }
+    }
+
+    /// Evaluates a condition that must result in a boolean.
+    fn evaluate_condition(&mut self, condition: &parser::statements::Expression) -> Option<bool> {
+        match self.evaluate_expression(condition) {
+            Some(values::Value::Bool(value)) => Some(value),
+            Some(value) => {
+                self.error(format!(
+                    "Expected a bool condition but found {}",
+                    value.get_type()
+                ));
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn evaluate_expression(
+        &mut self,
+        expression: &parser::statements::Expression,
+    ) -> Option<values::Value> {
+        match expression {
+            parser::statements::Expression::Identifier(name) => {
+                let value = self.environment.borrow().get(name);
+
+                // A named function used as a value
+                let value = match value {
+                    Some(value) => Some(value),
+                    None => self.function_value(&self.module.clone(), name),
+                };
+
+                if value.is_none() {
+                    self.error(format!("\"{}\" -> Undefined variable", name));
+                }
+
+                value
+            }
+            parser::statements::Expression::Literal { r#type, value } => {
+                let literal = values::Value::from_literal(r#type, value);
+
+                if literal.is_none() {
+                    self.error(format!("\"{}\" -> Invalid literal", value));
+                }
+
+                literal
+            }
+            parser::statements::Expression::Unary { operator, operand } => {
+                let operand: values::Value = self.evaluate_expression(operand)?;
+
+                match (operator, operand) {
+                    (
+                        lexer::tokens::TokenKind::OpAdd,
+                        operand @ (values::Value::Int(_)
+                        | values::Value::Sized(..)
+                        | values::Value::Float(_)
+                        | values::Value::Double(_)),
+                    ) => Some(operand),
+                    (lexer::tokens::TokenKind::OpSub, values::Value::Int(i)) => {
+                        Some(values::Value::Int(i.wrapping_neg()))
+                    }
+                    (lexer::tokens::TokenKind::OpSub, values::Value::Sized(kind, i)) => {
+                        self.sized_value(kind, Some(-i), -i)
+                    }
+                    (lexer::tokens::TokenKind::OpSub, values::Value::Float(f)) => {
+                        Some(values::Value::Float(-f))
+                    }
+                    (lexer::tokens::TokenKind::OpSub, values::Value::Double(d)) => {
+                        Some(values::Value::Double(-d))
+                    }
+                    (lexer::tokens::TokenKind::OpNot, values::Value::Bool(b)) => {
+                        Some(values::Value::Bool(!b))
+                    }
+                    (lexer::tokens::TokenKind::OpBitNot, values::Value::Int(i)) => {
+                        Some(values::Value::Int(!i))
+                    }
+                    (lexer::tokens::TokenKind::OpBitNot, values::Value::Sized(kind, i)) => {
+                        Some(values::Value::Sized(kind, kind.wrap(!i)))
+                    }
+                    (operator, operand) => {
+                        self.error(format!(
+                            "Invalid operand {} for unary operator {:?}",
+                            operand.get_type(),
+                            operator
+                        ));
+                        None
+                    }
+                }
+            }
+            parser::statements::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left: values::Value = self.evaluate_expression(left)?;
+                let right: values::Value = self.evaluate_expression(right)?;
+
+                self.evaluate_binary(operator, left, right)
+            }
+            parser::statements::Expression::Logical {
+                operator,
+                left,
+                right,
+            } => self.evaluate_logical(operator, left, right),
+            parser::statements::Expression::Member { object, member } => {
+                // A global of an imported module -> module.global
+                if let Some((module, global)) = self.module_item(expression) {
+                    let value: Option<values::Value> =
+                        match self.modules[&module].globals.borrow().get(&global) {
+                            Some(value) => Some(value),
+                            None => self.function_value(&module, &global),
+                        };
+
+                    if value.is_none() {
+                        self.error(format!("\"{}.{}\" -> Undefined variable", module, global));
+                    }
+
+                    return value;
+                }
+
+                let object: values::Object = match self.evaluate_expression(object)? {
+                    values::Value::Object(object) => object,
+                    // The message of a caught error -> error.message
+                    values::Value::Error(failure) if member == "message" => {
+                        return Some(values::Value::String(failure.message));
+                    }
+                    // An element of a tuple -> pair.0
+                    values::Value::Tuple(elements) => {
+                        let element: Option<values::Value> = member
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| elements.get(index).cloned());
+
+                        if element.is_none() {
+                            self.error(format!(
+                                "\"{}\" -> Out of the range of a tuple of {} elements",
+                                member,
+                                elements.len()
+                            ));
+                        }
+
+                        return element;
+                    }
+                    other => {
+                        self.error(format!(
+                            "Can't access a member of a value of type {}",
+                            other.get_type()
+                        ));
+                        return None;
+                    }
+                };
+                let field: Option<values::Value> = object.borrow().field(member).cloned();
+
+                if field.is_none() {
+                    self.error(format!(
+                        "\"{}\" -> Undefined field of {}",
+                        member,
+                        object.borrow().r#type
+                    ));
+                }
+
+                field
+            }
+            parser::statements::Expression::OptionalMember { object, member } => {
+                let object: values::Object = match self.evaluate_expression(object)? {
+                    values::Value::Null => {
+                        return Some(values::Value::Null);
+                    }
+                    values::Value::Object(object) => object,
+                    other => {
+                        self.error(format!(
+                            "Can't access a member of a value of type {}",
+                            other.get_type()
+                        ));
+                        return None;
+                    }
+                };
+
+                let field: Option<values::Value> = object.borrow().field(member).cloned();
+
+                if field.is_none() {
+                    self.error(format!(
+                        "\"{}\" -> Undefined field of {}",
+                        member,
+                        object.borrow().r#type
+                    ));
+                }
+
+                field
+            }
+            // A static member of a type -> Point::origin
+            parser::statements::Expression::StaticMember { .. } => {
+                let path: String = expression.path_name().unwrap_or_default();
+                let value: Option<values::Value> = self.environment.borrow().get(&path);
+
+                // A static method used as a value
+                let value: Option<values::Value> = match value {
+                    Some(value) => Some(value),
+                    None => self.function_value(&self.module.clone(), &path),
+                };
+
+                if value.is_none() {
+                    self.error(format!("\"{}\" -> Undefined static member", path));
+                }
+
+                value
+            }
+            parser::statements::Expression::Call {
+                callee, arguments, ..
+            } => {
+                let mut values: Arguments = Arguments::default();
+
+                for argument in arguments.iter().flat_map(|arguments| arguments.iter()) {
+                    match argument {
+                        parser::statements::Expression::NamedArgument { name, value, .. } => {
+                            let value: values::Value = self.evaluate_expression(value)?;
+                            values.named.push((name.clone(), value));
+                        }
+                        parser::statements::Expression::Spread { value, .. } => {
+                            match self.evaluate_expression(value)? {
+                                values::Value::Array(array) => {
+                                    values.positional.extend(array.borrow().iter().cloned())
+                                }
+                                value => {
+                                    self.error(format!("Can't spread {}", value.get_type()));
+                                    return None;
+                                }
+                            }
+                        }
+                        _ => values.positional.push(self.evaluate_expression(argument)?),
+                    }
+                }
+
+                self.call(callee, values)
+            }
+            parser::statements::Expression::NamedArgument { value, .. }
+            | parser::statements::Expression::Spread { value, .. } => {
+                self.evaluate_expression(value)
+            }
+            parser::statements::Expression::ArrayLiteral { elements } => {
+                let mut values: Vec<values::Value> = Vec::new();
+
+                for element in elements.iter().flat_map(|elements| elements.iter()) {
+                    values.push(self.evaluate_expression(element)?);
+                }
+
+                Some(values::Value::new_array(values))
+            }
+            parser::statements::Expression::Range {
+                from,
+                to,
+                inclusive,
+            } => match (
+                self.evaluate_expression(from)?,
+                self.evaluate_expression(to)?,
+            ) {
+                // Both bounds are converted to their common integer type -> 0..n for a u8 n
+                (from, to) if from.as_integer().is_some() && to.as_integer().is_some() => {
+                    match values::Value::promote(from.clone(), to.clone()) {
+                        Some((from, to)) => Some(values::Value::Range {
+                            from: from.as_integer()?,
+                            to: to.as_integer()?,
+                            inclusive: *inclusive,
+                            item: from.get_type(),
+                        }),
+                        None => {
+                            self.error(format!(
+                                "Can't mix {} and {} in the bounds of a range",
+                                from.get_type(),
+                                to.get_type()
+                            ));
+                            None
+                        }
+                    }
+                }
+                (from, to) => {
+                    self.error(format!(
+                        "Expected integer bounds for a range but found {} and {}",
+                        from.get_type(),
+                        to.get_type()
+                    ));
+                    None
+                }
+            },
+            parser::statements::Expression::Tuple { elements } => {
+                let mut values: Vec<values::Value> = Vec::new();
+
+                for element in elements {
+                    values.push(self.evaluate_expression(element)?);
+                }
+
+                Some(values::Value::Tuple(values))
+            }
+            parser::statements::Expression::ArrayAcess(acess) => self.evaluate_index(acess),
+            parser::statements::Expression::StructLiteral { name, fields } => {
+                let declared: Vec<parser::statements::Field> =
+                    match self.current_module().types.get(name) {
+                        Some(parser::statements::Statement::TypeDeclaration { fields, .. }) => {
+                            fields.clone()
+                        }
+                        _ => {
+                            self.error(format!("\"{}\" -> Undefined type", name));
+                            return None;
+                        }
+                    };
+
+                // The fields are stored in the order they were declared
+                let mut values: Vec<(String, values::Value)> = Vec::new();
+
+                for field in declared.iter() {
+                    let value: values::Value = match fields
+                        .iter()
+                        .find(|(name, _)| *name == field.name)
+                    {
+                        Some((_, value)) => self.evaluate_expression(value)?.coerce(&field.r#type),
+                        None => self.default_value(&field.r#type),
+                    };
+
+                    values.push((field.name.clone(), value));
+                }
+
+                Some(values::Value::new_object(
+                    self.module.clone(),
+                    name.clone(),
+                    values,
+                ))
+            }
+            parser::statements::Expression::Block { body, value, .. } => {
+                let scope = Rc::new(RefCell::new(environment::Environment::new(Some(
+                    self.environment.clone(),
+                ))));
+                let previous = std::mem::replace(&mut self.environment, scope);
+
+                let value: Option<values::Value> = match self.execute_statements(body) {
+                    Flow::Normal => match value {
+                        Some(value) => self.evaluate_expression(value),
+                        None => Some(values::Value::Void),
+                    },
+                    Flow::Abort => None,
+                    flow => {
+                        self.unwinding = Some(flow);
+                        None
+                    }
+                };
+
+                self.environment = previous;
+                value
+            }
+            parser::statements::Expression::Loop { label, body, .. } => loop {
+                match self.execute_loop_body(label.as_deref(), Some(body)) {
+                    Flow::Normal | Flow::Continue(None) => {}
+                    Flow::Break { label: None, value } => break Some(value),
+                    Flow::Abort => break None,
+                    flow => {
+                        self.unwinding = Some(flow);
+                        break None;
+                    }
+                }
+            },
+            parser::statements::Expression::If {
+                condition,
+                body,
+                alternate,
+                ..
+            } => {
+                if self.evaluate_condition(condition)? {
+                    self.evaluate_expression(body)
+                } else {
+                    match alternate {
+                        Some(alternate) => self.evaluate_expression(alternate),
+                        None => Some(values::Value::Void),
+                    }
+                }
+            }
+            parser::statements::Expression::Propagate { value, .. } => {
+                match self.evaluate_expression(value)? {
+                    values::Value::Error(failure) => {
+                        self.unwinding = Some(Flow::Throw(failure));
+                        None
+                    }
+                    value => Some(value),
+                }
+            }
+            parser::statements::Expression::Cast { value, r#type } => {
+                let value: values::Value = self.evaluate_expression(value)?;
+                let from: internals::types::Types = value.get_type();
+                let cast: Option<values::Value> = value.cast(&self.underlying_type(r#type));
+
+                if cast.is_none() {
+                    self.error(format!("Can't convert this {} to {}", from, r#type));
+                }
+
+                cast
+            }
+            parser::statements::Expression::Lambda {
+                params,
+                r#type,
+                body,
+                ..
+            } => Some(values::Value::Function(Rc::new(values::Closure {
+                module: self.module.clone(),
+                params: params.clone(),
+                r#type: r#type.clone(),
+                body: Rc::new(body.deref().clone()),
+                environment: self.environment.clone(),
+            }))),
+        }
+    }
+
+    /// Builds a closure over the globals of a module from one of its functions.
+    fn function_value(&self, module: &str, name: &str) -> Option<values::Value> {
+        let module_state: &Module = self.modules.get(module)?;
+
+        match module_state.functions.get(name)? {
+            parser::statements::Statement::FunctionDeclaration {
+                params,
+                r#type,
+                body: Some(body),
+                ..
+            } => Some(values::Value::Function(Rc::new(values::Closure {
+                module: module.to_string(),
+                params: params.clone().unwrap_or_default(),
+                r#type: r#type.clone(),
+                body: Rc::new(body.deref().clone()),
+                environment: module_state.globals.clone(),
+            }))),
+            _ => None,
+        }
+    }
+
+    /// Evaluates arithmetic and bitwise operators -> '+', '-', '*', '/', '%', '&', '|', '^', "<<", ">>"
+    fn evaluate_binary(
+        &mut self,
+        operator: &lexer::tokens::TokenKind,
+        left: values::Value,
+        right: values::Value,
+    ) -> Option<values::Value> {
+        // An instance whose type overloads the operator -> over +(other: Self) -> Self
+        if let Some(overload) = operator.overload()
+            && self.has_method(&left, overload)
+        {
+            return self.call_method(
+                left,
+                overload,
+                Arguments {
+                    positional: vec![right],
+                    ..Default::default()
+                },
+            );
+        }
+
+        if let (
+            lexer::tokens::TokenKind::OpAdd,
+            values::Value::String(left),
+            values::Value::String(right),
+        ) = (operator, &left, &right)
+        {
+            return Some(values::Value::String(format!("{}{}", left, right)));
+        }
+
+        let (left_type, right_type) = (left.get_type(), right.get_type());
+
+        match values::Value::promote(left, right) {
+            Some((values::Value::Int(l), values::Value::Int(r))) => match operator {
+                lexer::tokens::TokenKind::OpAdd => Some(values::Value::Int(l.wrapping_add(r))),
+                lexer::tokens::TokenKind::OpSub => Some(values::Value::Int(l.wrapping_sub(r))),
+                lexer::tokens::TokenKind::OpMul => Some(values::Value::Int(l.wrapping_mul(r))),
+                lexer::tokens::TokenKind::OpDiv | lexer::tokens::TokenKind::OpMod if r == 0 => {
+                    self.error("Division by zero".to_string());
+                    None
+                }
+                lexer::tokens::TokenKind::OpDiv => Some(values::Value::Int(l.wrapping_div(r))),
+                lexer::tokens::TokenKind::OpMod => Some(values::Value::Int(l.wrapping_rem(r))),
+                lexer::tokens::TokenKind::OpBitAnd => Some(values::Value::Int(l & r)),
+                lexer::tokens::TokenKind::OpBitOr => Some(values::Value::Int(l | r)),
+                lexer::tokens::TokenKind::OpBitXor => Some(values::Value::Int(l ^ r)),
+                // A shift by a negative amount or by the whole width has no result
+                lexer::tokens::TokenKind::OpShl | lexer::tokens::TokenKind::OpShr
+                    if !(0..64).contains(&r) =>
+                {
+                    self.error(format!("Invalid shift amount {}", r));
+                    None
+                }
+                lexer::tokens::TokenKind::OpShl => Some(values::Value::Int(l << r)),
+                lexer::tokens::TokenKind::OpShr => Some(values::Value::Int(l >> r)),
+                _ => None,
+            },
+            Some((values::Value::Sized(kind, l), values::Value::Sized(_, r))) => {
+                self.evaluate_sized(operator, kind, l, r)
+            }
+            Some((values::Value::Float(l), values::Value::Float(r))) => match operator {
+                lexer::tokens::TokenKind::OpAdd => Some(values::Value::Float(l + r)),
+                lexer::tokens::TokenKind::OpSub => Some(values::Value::Float(l - r)),
+                lexer::tokens::TokenKind::OpMul => Some(values::Value::Float(l * r)),
+                lexer::tokens::TokenKind::OpDiv => Some(values::Value::Float(l / r)),
+                lexer::tokens::TokenKind::OpMod => Some(values::Value::Float(l % r)),
+                _ => None,
+            },
+            Some((values::Value::Double(l), values::Value::Double(r))) => match operator {
+                lexer::tokens::TokenKind::OpAdd => Some(values::Value::Double(l + r)),
+                lexer::tokens::TokenKind::OpSub => Some(values::Value::Double(l - r)),
+                lexer::tokens::TokenKind::OpMul => Some(values::Value::Double(l * r)),
+                lexer::tokens::TokenKind::OpDiv => Some(values::Value::Double(l / r)),
+                lexer::tokens::TokenKind::OpMod => Some(values::Value::Double(l % r)),
+                _ => None,
+            },
+            _ => {
+                self.error(format!(
+                    "Invalid operands {} and {} for operator {:?}",
+                    left_type, right_type, operator
+                ));
+                None
+            }
+        }
+    }
+
+    /// Evaluates an arithmetic or bitwise operator on two integers of a sized type.
+    fn evaluate_sized(
+        &mut self,
+        operator: &lexer::tokens::TokenKind,
+        kind: internals::types::IntKind,
+        left: i128,
+        right: i128,
+    ) -> Option<values::Value> {
+        // An int converted to the type of the other side can be out of its range -> u8 + 300
+        let l: i128 = self.sized_value(kind, Some(left), left)?.as_integer()?;
+        let r: i128 = self.sized_value(kind, Some(right), right)?.as_integer()?;
+
+        match operator {
+            lexer::tokens::TokenKind::OpAdd => self.sized_value(kind, l.checked_add(r), l + r),
+            lexer::tokens::TokenKind::OpSub => self.sized_value(kind, l.checked_sub(r), l - r),
+            lexer::tokens::TokenKind::OpMul => {
+                self.sized_value(kind, l.checked_mul(r), l.wrapping_mul(r))
+            }
+            lexer::tokens::TokenKind::OpDiv | lexer::tokens::TokenKind::OpMod if r == 0 => {
+                self.error("Division by zero".to_string());
+                None
+            }
+            lexer::tokens::TokenKind::OpDiv => self.sized_value(kind, Some(l / r), l / r),
+            lexer::tokens::TokenKind::OpMod => Some(values::Value::Sized(kind, l % r)),
+            lexer::tokens::TokenKind::OpBitAnd => Some(values::Value::Sized(kind, l & r)),
+            lexer::tokens::TokenKind::OpBitOr => Some(values::Value::Sized(kind, l | r)),
+            lexer::tokens::TokenKind::OpBitXor => Some(values::Value::Sized(kind, l ^ r)),
+            lexer::tokens::TokenKind::OpShl | lexer::tokens::TokenKind::OpShr
+                if !(0..kind.bits() as i128).contains(&r) =>
+            {
+                self.error(format!("Invalid shift amount {}", r));
+                None
+            }
+            // The bits shifted out are lost, like with an int
+            lexer::tokens::TokenKind::OpShl => Some(values::Value::Sized(kind, kind.wrap(l << r))),
+            lexer::tokens::TokenKind::OpShr => Some(values::Value::Sized(kind, l >> r)),
+            _ => None,
+        }
+    }
+
+    /// Builds a sized integer from the result of an operation, the wrapped result is used when it
+    /// doesn't fit in its type in a release build, a debug build stops the program.
+    fn sized_value(
+        &mut self,
+        kind: internals::types::IntKind,
+        result: Option<i128>,
+        wrapped: i128,
+    ) -> Option<values::Value> {
+        match result {
+            Some(value) if kind.contains(value) => Some(values::Value::Sized(kind, value)),
+            _ if !self.release => {
+                self.error(format!("Overflow, the result doesn't fit in {}", kind));
+                None
+            }
+            _ => Some(values::Value::Sized(kind, kind.wrap(wrapped))),
+        }
+    }
+
+    /// Evaluates logical and comparison operators, 'and' and 'or' short-circuit.
+    fn evaluate_logical(
+        &mut self,
+        operator: &lexer::tokens::TokenKind,
+        left: &parser::statements::Expression,
+        right: &parser::statements::Expression,
+    ) -> Option<values::Value> {
+        match operator {
+            lexer::tokens::TokenKind::OpAnd => {
+                if !self.evaluate_condition(left)? {
+                    return Some(values::Value::Bool(false));
+                }
+
+                Some(values::Value::Bool(self.evaluate_condition(right)?))
+            }
+            lexer::tokens::TokenKind::OpOr => {
+                if self.evaluate_condition(left)? {
+                    return Some(values::Value::Bool(true));
+                }
+
+                Some(values::Value::Bool(self.evaluate_condition(right)?))
+            }
+            // The right side is only evaluated when the left one is null
+            lexer::tokens::TokenKind::OpCoalesce => match self.evaluate_expression(left)? {
+                values::Value::Null => self.evaluate_expression(right),
+                value => Some(value),
+            },
+            _ => {
+                let left: values::Value = self.evaluate_expression(left)?;
+                let right: values::Value = self.evaluate_expression(right)?;
+
+                // An instance whose type overloads the comparison, "!=" is the opposite of "=="
+                if let Some(overload) = operator.overload()
+                    && right != values::Value::Null
+                    && self.has_method(&left, overload)
+                {
+                    let result: values::Value = self.call_method(
+                        left,
+                        overload,
+                        Arguments {
+                            positional: vec![right],
+                            ..Default::default()
+                        },
+                    )?;
+
+                    return match (operator, result) {
+                        (lexer::tokens::TokenKind::OpNeq, values::Value::Bool(equal)) => {
+                            Some(values::Value::Bool(!equal))
+                        }
+                        (_, result) => Some(result),
+                    };
+                }
+
+                let (left_type, right_type) = (left.get_type(), right.get_type());
+
+                let ordering: Option<std::cmp::Ordering> =
+                    match values::Value::promote(left.clone(), right.clone()) {
+                        Some((l, r)) => l.partial_cmp_value(&r),
+                        None => left.partial_cmp_value(&right),
+                    };
+
+                let result: Option<bool> = match (operator, ordering) {
+                    (lexer::tokens::TokenKind::OpEq, ordering) => {
+                        Some(ordering == Some(std::cmp::Ordering::Equal))
+                    }
+                    (lexer::tokens::TokenKind::OpNeq, ordering) => {
+                        Some(ordering != Some(std::cmp::Ordering::Equal))
+                    }
+                    (lexer::tokens::TokenKind::OpLt, Some(ordering)) => Some(ordering.is_lt()),
+                    (lexer::tokens::TokenKind::OpLe, Some(ordering)) => Some(ordering.is_le()),
+                    (lexer::tokens::TokenKind::OpGt, Some(ordering)) => Some(ordering.is_gt()),
+                    (lexer::tokens::TokenKind::OpGe, Some(ordering)) => Some(ordering.is_ge()),
+                    _ => None,
+                };
+
+                if result.is_none() {
+                    self.error(format!(
+                        "Cannot compare {} and {} with operator {:?}",
+                        left_type, right_type, operator
+                    ));
+                }
+
+                result.map(values::Value::Bool)
+            }
+        }
+    }
+
+    /// Calls the function or method a callee expression refers to.
+    fn call(
+        &mut self,
+        callee: &parser::statements::Expression,
+        arguments: Arguments,
+    ) -> Option<values::Value> {
+        // A function of an imported module runs inside that module -> module.function()
+        if let Some((module, function)) = self.module_item(callee) {
+            let previous_module: String = std::mem::replace(&mut self.module, module);
+            let result: Option<values::Value> = self.call_function(&function, arguments);
+            self.module = previous_module;
+
+            return result;
+        }
+
+        // Variables holding a function shadow the named functions
+        if let parser::statements::Expression::Identifier(name) = callee {
+            let value: Option<values::Value> = self.environment.borrow().get(name);
+
+            if let Some(value) = value {
+                return self.call_value(value, name, arguments);
+            }
+        }
+
+        // Named functions, qualified ones included -> std.console.printl
+        if let Some(name) = callee.path_name()
+            && (BUILTINS.contains(&name.as_str())
+                || self.current_module().functions.contains_key(&name))
+        {
+            return self.call_function(&name, arguments);
+        }
+
+        match callee {
+            parser::statements::Expression::Member { object, member }
+            | parser::statements::Expression::OptionalMember { object, member } => {
+                let receiver: values::Value = self.evaluate_expression(object)?;
+
+                // Nothing is called on null -> object?.method()
+                if receiver == values::Value::Null
+                    && matches!(
+                        callee,
+                        parser::statements::Expression::OptionalMember { .. }
+                    )
+                {
+                    return Some(values::Value::Null);
+                }
+
+                // A field holding a function -> object.callback()
+                if let values::Value::Object(object) = &receiver {
+                    let field: Option<values::Value> = object.borrow().field(member).cloned();
+
+                    if let Some(value) = field {
+                        return self.call_value(value, member, arguments);
+                    }
+                }
+
+                self.call_method(receiver, member, arguments)
+            }
+            parser::statements::Expression::Identifier(name) => {
+                self.error(format!("\"{}\" -> Undefined function", name));
+                None
+            }
+            // Any expression giving a function -> make_adder(1)(2)
+            _ => {
+                let value: values::Value = self.evaluate_expression(callee)?;
+                self.call_value(value, "function", arguments)
+            }
+        }
+    }
+
+    /// Calls a value, only functions can be called.
+    fn call_value(
+        &mut self,
+        value: values::Value,
+        name: &str,
+        arguments: Arguments,
+    ) -> Option<values::Value> {
+        match value {
+            values::Value::Function(closure) => {
+                let previous_module: String =
+                    std::mem::replace(&mut self.module, closure.module.clone());
+                let result: Option<values::Value> = self.run(
+                    name,
+                    &closure.params,
+                    &closure.r#type,
+                    &closure.body,
+                    closure.environment.clone(),
+                    arguments,
+                    None,
+                );
+                self.module = previous_module;
+
+                result
+            }
+            value => {
+                self.error(format!(
+                    "\"{}\" -> {} isn't a function",
+                    name,
+                    value.get_type()
+                ));
+                None
+            }
+        }
+    }
+
+    /// Returns the declaration of a method of a type.
+    fn method_declaration(
+        &self,
+        module: &str,
+        r#type: &str,
+        method: &str,
+    ) -> Option<parser::statements::Statement> {
+        match self.modules[module].types.get(r#type) {
+            Some(parser::statements::Statement::TypeDeclaration { methods, .. }) => methods
+                .iter()
+                .find(|declaration| {
+                    matches!(
+                        declaration,
+                        parser::statements::Statement::FunctionDeclaration { name, .. }
+                            if name == method
+                    )
+                })
+                .cloned(),
+            _ => None,
+        }
+    }
+
+    /// Checks if a value is an instance of a type with the given method.
+    fn has_method(&self, value: &values::Value, method: &str) -> bool {
+        match value {
+            values::Value::Object(object) => {
+                let instance = object.borrow();
+                self.method_declaration(&instance.module, &instance.r#type, method)
+                    .is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Calls a built-in method on a value -> receiver.method(arguments)
+    fn call_method(
+        &mut self,
+        receiver: values::Value,
+        method: &str,
+        arguments: Arguments,
+    ) -> Option<values::Value> {
+        if let values::Value::Object(object) = &receiver {
+            let (module, r#type) = {
+                let instance = object.borrow();
+                (instance.module.clone(), instance.r#type.clone())
+            };
+
+            let declaration: Option<parser::statements::Statement> =
+                self.method_declaration(&module, &r#type, method);
+
+            return match declaration {
+                // A method runs inside the module of its type
+                Some(declaration) => {
+                    let previous_module: String = std::mem::replace(&mut self.module, module);
+                    let result: Option<values::Value> =
+                        self.invoke(method, &declaration, arguments, Some(receiver));
+                    self.module = previous_module;
+
+                    result
+                }
+                None => {
+                    self.error(format!("\"{}\" -> Undefined method of {}", method, r#type));
+                    None
+                }
+            };
+        }
+
+        match (&receiver, method, arguments.positional.as_slice()) {
+            (values::Value::String(string), "len", []) => {
+                Some(values::Value::Int(string.chars().count() as i64))
+            }
+            (values::Value::Array(array), "len", []) => {
+                Some(values::Value::Int(array.borrow().len() as i64))
+            }
+            // Integers that wrap around instead of overflowing -> x.wrapping_add(1)
+            (
+                values::Value::Int(_) | values::Value::Sized(..),
+                "wrapping_add" | "wrapping_sub" | "wrapping_mul",
+                [other],
+            ) if other.as_integer().is_some() => {
+                let (l, r) = (receiver.as_integer()?, other.as_integer()?);
+
+                let result: i128 = match method {
+                    "wrapping_add" => l.wrapping_add(r),
+                    "wrapping_sub" => l.wrapping_sub(r),
+                    _ => l.wrapping_mul(r),
+                };
+
+                match receiver {
+                    values::Value::Sized(kind, _) => {
+                        Some(values::Value::Sized(kind, kind.wrap(result)))
+                    }
+                    _ => Some(values::Value::Int(result as i64)),
+                }
+            }
+            _ => {
+                self.error(format!(
+                    "\"{}\" -> Undefined method of {}",
+                    method,
+                    receiver.get_type()
+                ));
+                None
+            }
+        }
+    }
+
+    /// Calls a built-in or user defined function with already evaluated arguments.
+    fn call_function(&mut self, name: &str, arguments: Arguments) -> Option<values::Value> {
+        match name {
+            "print" | "println" | "std.console.print" | "std.console.printl" => {
+                let text: String = arguments
+                    .positional
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect();
+
+                if name.ends_with("print") {
+                    print!("{}", text);
+                } else {
+                    println!("{}", text);
+                }
+
+                return Some(values::Value::Void);
+            }
+            _ => {}
+        }
+
+        match self.current_module().functions.get(name).cloned() {
+            Some(declaration) => self.invoke(name, &declaration, arguments, None),
+            None => {
+                self.error(format!("\"{}\" -> Undefined function", name));
+                None
+            }
+        }
+    }
+
+    /// Runs the body of a function declaration, methods get their instance as "self".
+    fn invoke(
+        &mut self,
+        name: &str,
+        declaration: &parser::statements::Statement,
+        arguments: Arguments,
+        receiver: Option<values::Value>,
+    ) -> Option<values::Value> {
+        let (params, r#type, body) = match declaration {
+            parser::statements::Statement::FunctionDeclaration {
+                params,
+                r#type,
+                body: Some(body),
+                ..
+            } => (params.clone().unwrap_or_default(), r#type, body),
+            _ => {
+                self.error(format!("\"{}\" -> Undefined function", name));
+                return None;
+            }
+        };
+
+        let globals = self.current_module().globals.clone();
+        self.run(name, &params, r#type, body, globals, arguments, receiver)
+    }
+
+    /// Runs a function body in a new scope inside its parent scope.
+    #[allow(clippy::too_many_arguments)]
+    fn run(
+        &mut self,
+        name: &str,
+        params: &[parser::statements::FuncParam],
+        r#type: &internals::types::Types,
+        body: &[parser::statements::Statement],
+        parent: Rc<RefCell<environment::Environment>>,
+        arguments: Arguments,
+        receiver: Option<values::Value>,
+    ) -> Option<values::Value> {
+        let mut positional: Vec<values::Value> = arguments.positional;
+
+        // The variadic parameter takes the arguments left after the others
+        let variadic: Option<values::Value> = match params.last() {
+            Some(param) if param.variadic && positional.len() >= params.len() => Some(
+                values::Value::new_array(positional.split_off(params.len() - 1)),
+            ),
+            _ => None,
+        };
+
+        if positional.len() > params.len() {
+            self.error(format!(
+                "\"{}\" -> Expected {} arguments but found {}",
+                name,
+                params.len(),
+                positional.len()
+            ));
+            return None;
+        }
+
+        // The value of each parameter, in the order they were declared
+        let mut bound: Vec<Option<values::Value>> = vec![None; params.len()];
+
+        for (slot, argument) in bound.iter_mut().zip(positional) {
+            *slot = Some(argument);
+        }
+
+        if let Some(variadic) = variadic {
+            bound[params.len() - 1] = Some(variadic);
+        }
+
+        for (argument, value) in arguments.named {
+            match params.iter().position(|param| param.name == argument) {
+                Some(index) if bound[index].is_none() => bound[index] = Some(value),
+                Some(_) => {
+                    self.error(format!(
+                        "\"{}\" -> Parameter \"{}\" is given more than once",
+                        name, argument
+                    ));
+                    return None;
+                }
+                None => {
+                    self.error(format!(
+                        "\"{}\" -> Unknown parameter \"{}\"",
+                        name, argument
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        if self.depth >= MAX_CALL_DEPTH {
+            self.error(format!("\"{}\" -> Maximum call depth exceeded", name));
+            return None;
+        }
+
+        let mut scope = environment::Environment::new(Some(parent));
+        if let Some(receiver) = receiver {
+            scope.define("self".to_string(), receiver);
+        }
+
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(scope)));
+        let position = self.position.clone();
+        self.depth += 1;
+
+        let flow: Flow = match self.bind_params(name, params, bound) {
+            Some(_) => self.execute_statements(body),
+            None => Flow::Abort,
+        };
+
+        self.depth -= 1;
+        self.environment = previous;
+        self.position = position;
+
+        match flow {
+            Flow::Return(value) => Some(value.coerce(r#type)),
+            // The error is the result of the call, "?" decides where it goes
+            Flow::Throw(failure) => Some(values::Value::Error(failure)),
+            Flow::Normal | Flow::Break { .. } | Flow::Continue(_) => Some(values::Value::Void),
+            Flow::Abort => None,
+        }
+    }
+
+    /// Defines the parameters in the scope of a call, the missing ones take their default value.
+    fn bind_params(
+        &mut self,
+        name: &str,
+        params: &[parser::statements::FuncParam],
+        bound: Vec<Option<values::Value>>,
+    ) -> Option<()> {
+        for (param, value) in params.iter().zip(bound) {
+            // Defaults are evaluated inside the call, they can use the previous parameters
+            let value: values::Value = match (value, &param.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => self.evaluate_expression(default)?,
+                (None, None) if param.variadic => values::Value::new_array(Vec::new()),
+                (None, None) => {
+                    self.error(format!(
+                        "\"{}\" -> Missing argument for parameter \"{}\"",
+                        name, param.name
+                    ));
+                    return None;
+                }
+            };
+
+            self.environment
+                .borrow_mut()
+                .define(param.name.clone(), value.coerce(&param.r#type));
+        }
+
+        Some(())
     }
 }
@@ -0,0 +1,488 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::*;
+
+/// The elements of an array, shared by every value that refers to it.
+pub type Array = Rc<RefCell<Vec<Value>>>;
+
+/// An instance of a struct or class, shared by every value that refers to it.
+pub type Object = Rc<RefCell<Instance>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    // The module the type of the instance was declared in
+    pub module: String,
+    pub r#type: String,
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Instance {
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.fields
+            .iter_mut()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// An error thrown by a function, with where it was thrown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub message: String,
+    pub position: Option<internals::Position>,
+}
+
+/// A function used as a value, with the scope it was created in.
+pub struct Closure {
+    // The module the function was declared in
+    pub module: String,
+    pub params: Vec<parser::statements::FuncParam>,
+    pub r#type: internals::types::Types,
+    pub body: Rc<Vec<parser::statements::Statement>>,
+    pub environment: Rc<RefCell<evaluator::environment::Environment>>,
+}
+
+impl Closure {
+    /// Returns the type of the function -> func(int) -> int
+    pub fn get_type(&self) -> internals::types::Types {
+        internals::types::Types::Function(
+            self.params
+                .iter()
+                .map(|param| param.r#type.clone())
+                .collect(),
+            Box::new(self.r#type.clone()),
+        )
+    }
+}
+
+// The captured scope can hold the closure itself, so it isn't printed
+impl std::fmt::Debug for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Closure({})", self.get_type())
+    }
+}
+
+// Functions are only equal to themselves
+impl PartialEq for Closure {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Void,
+    Null,
+    Int(i64),
+    /// An integer of a sized type, wide enough for the values of every one of them
+    Sized(internals::types::IntKind, i128),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    Char(char),
+    String(String),
+    Array(Array),
+    /// Tuples are copied like numbers, their elements can't change
+    Tuple(Vec<Value>),
+    /// The bounds are of the type of the items, int or a sized integer type
+    Range {
+        from: i128,
+        to: i128,
+        inclusive: bool,
+        item: internals::types::Types,
+    },
+    Object(Object),
+    Function(Rc<Closure>),
+    Error(Failure),
+}
+
+impl Value {
+    /// Builds an array from its elements.
+    pub fn new_array(elements: Vec<Value>) -> Self {
+        Value::Array(Rc::new(RefCell::new(elements)))
+    }
+
+    /// Builds an instance of a type from the values of its fields.
+    pub fn new_object(module: String, r#type: String, fields: Vec<(String, Value)>) -> Self {
+        Value::Object(Rc::new(RefCell::new(Instance {
+            module,
+            r#type,
+            fields,
+        })))
+    }
+
+    /// Builds a value from a literal expression.
+    pub fn from_literal(kind: &lexer::tokens::TokenKind, value: &str) -> Option<Self> {
+        match kind {
+            lexer::tokens::TokenKind::Number => {
+                if value.contains(['.', 'e', 'E']) {
+                    value.parse::<f64>().ok().map(Value::Double)
+                } else if let Ok(integer) = value.parse::<i64>() {
+                    Some(Value::Int(integer))
+                } else {
+                    // Too big for an int -> 18446744073709551615
+                    value.parse::<u64>().ok().map(|integer| {
+                        Value::Sized(internals::types::IntKind::U64, integer as i128)
+                    })
+                }
+            }
+            lexer::tokens::TokenKind::CharLiteral => {
+                let chars: Vec<char> = unescape(&value[1..value.len() - 1]).chars().collect();
+                match chars.as_slice() {
+                    [c] => Some(Value::Char(*c)),
+                    _ => None,
+                }
+            }
+            lexer::tokens::TokenKind::StringLiteral => {
+                Some(Value::String(unescape(&value[1..value.len() - 1])))
+            }
+            lexer::tokens::TokenKind::TyBool => Some(Value::Bool(value == "true")),
+            lexer::tokens::TokenKind::Null => Some(Value::Null),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the value.
+    pub fn get_type(&self) -> internals::types::Types {
+        match self {
+            Value::Void => internals::types::Types::Void,
+            Value::Null => internals::types::Types::Null,
+            Value::Int(_) => internals::types::Types::Int,
+            Value::Sized(kind, _) => internals::types::Types::Sized(*kind),
+            Value::Float(_) => internals::types::Types::Float,
+            Value::Double(_) => internals::types::Types::Double,
+            Value::Bool(_) => internals::types::Types::Bool,
+            Value::Char(_) => internals::types::Types::Char,
+            Value::String(_) => internals::types::Types::String,
+            Value::Array(array) => {
+                let array = array.borrow();
+                let element: internals::types::Types = match array.first() {
+                    Some(element) => element.get_type(),
+                    None => internals::types::Types::Unknown,
+                };
+
+                internals::types::Types::Array(Box::new(element), array.len())
+            }
+            Value::Tuple(elements) => {
+                internals::types::Types::Tuple(elements.iter().map(Value::get_type).collect())
+            }
+            Value::Range { item, .. } => internals::types::Types::Range(Box::new(item.clone())),
+            Value::Object(object) => {
+                internals::types::Types::Custom(object.borrow().r#type.clone())
+            }
+            Value::Function(closure) => closure.get_type(),
+            Value::Error(_) => internals::types::Types::Error,
+        }
+    }
+
+    /// The value of a variable declared without an initializer.
+    pub fn default_for(r#type: &internals::types::Types) -> Self {
+        match r#type {
+            internals::types::Types::Int => Value::Int(0),
+            internals::types::Types::Sized(kind) => Value::Sized(*kind, 0),
+            internals::types::Types::Float => Value::Float(0.0),
+            internals::types::Types::Double => Value::Double(0.0),
+            internals::types::Types::Bool => Value::Bool(false),
+            internals::types::Types::Char => Value::Char('\0'),
+            internals::types::Types::String => Value::String(String::new()),
+            internals::types::Types::Array(element, size) => {
+                Value::new_array((0..*size).map(|_| Value::default_for(element)).collect())
+            }
+            internals::types::Types::Slice(_) => Value::new_array(Vec::new()),
+            internals::types::Types::Tuple(types) => {
+                Value::Tuple(types.iter().map(Value::default_for).collect())
+            }
+            internals::types::Types::Optional(_) => Value::Null,
+            internals::types::Types::Alias(_, r#type) => Value::default_for(r#type),
+            _ => Value::Void,
+        }
+    }
+
+    /// Builds an integer of a type, int or a sized integer type -> the items of a range
+    pub fn new_integer(r#type: &internals::types::Types, integer: i128) -> Self {
+        match r#type {
+            internals::types::Types::Sized(kind) => Value::Sized(*kind, integer),
+            _ => Value::Int(integer as i64),
+        }
+    }
+
+    /// Returns the value if it is an integer, of any type.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Int(i) => Some(*i as i128),
+            Value::Sized(_, i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a double if it is a number.
+    pub fn as_double(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Sized(_, i) => Some(*i as f64),
+            Value::Float(f) => Some(*f as f64),
+            Value::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Converts two numbers to their common type -> int < float < double
+    ///
+    /// The integers can be out of the range of their common sized type -> 300 for u8 + 300
+    pub fn promote(left: Self, right: Self) -> Option<(Self, Self)> {
+        match (&left, &right) {
+            (Value::Int(_), Value::Int(_)) => Some((left, right)),
+            (Value::Int(_) | Value::Sized(..), Value::Int(_) | Value::Sized(..)) => {
+                match left.get_type().common_numeric(&right.get_type())? {
+                    internals::types::Types::Sized(kind) => Some((
+                        Value::Sized(kind, left.as_integer()?),
+                        Value::Sized(kind, right.as_integer()?),
+                    )),
+                    _ => None,
+                }
+            }
+            (Value::Double(_), _) | (_, Value::Double(_)) => Some((
+                Value::Double(left.as_double()?),
+                Value::Double(right.as_double()?),
+            )),
+            (Value::Float(_), _) | (_, Value::Float(_)) => Some((
+                Value::Float(left.as_double()? as f32),
+                Value::Float(right.as_double()? as f32),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Compares two values of the same type.
+    pub fn partial_cmp_value(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Value::Int(l), Value::Int(r)) => l.partial_cmp(r),
+            // Integers of different types are compared by their values -> i32 and u32
+            (Value::Int(_) | Value::Sized(..), Value::Int(_) | Value::Sized(..)) => {
+                self.as_integer()?.partial_cmp(&other.as_integer()?)
+            }
+            (Value::Float(l), Value::Float(r)) => l.partial_cmp(r),
+            (Value::Double(l), Value::Double(r)) => l.partial_cmp(r),
+            (Value::Bool(l), Value::Bool(r)) => l.partial_cmp(r),
+            (Value::Char(l), Value::Char(r)) => l.partial_cmp(r),
+            (Value::String(l), Value::String(r)) => l.partial_cmp(r),
+            (Value::Void, Value::Void) | (Value::Null, Value::Null) => {
+                Some(std::cmp::Ordering::Equal)
+            }
+            // Tuples are compared element by element, the first difference decides
+            (Value::Tuple(l), Value::Tuple(r)) if l.len() == r.len() => {
+                for (left, right) in l.iter().zip(r) {
+                    match left.partial_cmp_value(right)? {
+                        std::cmp::Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+
+                Some(std::cmp::Ordering::Equal)
+            }
+            (
+                Value::Range {
+                    from: l_from,
+                    to: l_to,
+                    inclusive: l_inclusive,
+                    ..
+                },
+                Value::Range {
+                    from: r_from,
+                    to: r_to,
+                    inclusive: r_inclusive,
+                    ..
+                },
+            ) if (l_from, l_to, l_inclusive) == (r_from, r_to, r_inclusive) => {
+                Some(std::cmp::Ordering::Equal)
+            }
+            // Instances are only equal or not
+            (Value::Object(l), Value::Object(r)) if l == r => Some(std::cmp::Ordering::Equal),
+            (Value::Function(l), Value::Function(r)) if l == r => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        }
+    }
+
+    /// Converts the value with an explicit cast, following the conversion matrix of the types.
+    pub fn cast(self, r#type: &internals::types::Types) -> Option<Self> {
+        if self.get_type().conversion(r#type) == internals::types::Conversion::Invalid {
+            return None;
+        }
+
+        match (self, r#type) {
+            (value, internals::types::Types::Alias(_, r#type)) => value.cast(r#type),
+            (value, internals::types::Types::String) => Some(Value::String(value.to_string())),
+            (Value::Float(f), internals::types::Types::Int) => Some(Value::Int(f as i64)),
+            (Value::Double(d), internals::types::Types::Int) => Some(Value::Int(d as i64)),
+            (Value::Char(c), internals::types::Types::Int) => Some(Value::Int(c as i64)),
+            (Value::Bool(b), internals::types::Types::Int) => Some(Value::Int(b as i64)),
+            (Value::Int(i), internals::types::Types::Bool) => Some(Value::Bool(i != 0)),
+            // Not every number is a code point
+            (Value::Int(i), internals::types::Types::Char) => u32::try_from(i)
+                .ok()
+                .and_then(char::from_u32)
+                .map(Value::Char),
+            // The integers keep the bits that fit, the numbers with a fraction stop at the bounds
+            (value, internals::types::Types::Sized(kind)) => {
+                let integer: i128 = match value {
+                    Value::Float(f) => f as i128,
+                    Value::Double(d) => d as i128,
+                    Value::Char(c) => c as i128,
+                    Value::Bool(b) => b as i128,
+                    value => {
+                        return Some(Value::Sized(*kind, kind.wrap(value.as_integer()?)));
+                    }
+                };
+
+                let (min, max) = kind.range();
+                Some(Value::Sized(*kind, integer.clamp(min, max)))
+            }
+            (Value::Sized(_, i), internals::types::Types::Int) => Some(Value::Int(i as i64)),
+            (Value::Sized(_, i), internals::types::Types::Bool) => Some(Value::Bool(i != 0)),
+            (Value::Sized(_, i), internals::types::Types::Char) => u32::try_from(i)
+                .ok()
+                .and_then(char::from_u32)
+                .map(Value::Char),
+            (value, r#type) => Some(value.coerce(r#type)),
+        }
+    }
+
+    /// Converts the value to the declared type, widening numbers when needed.
+    pub fn coerce(self, r#type: &internals::types::Types) -> Self {
+        match (self, r#type) {
+            (Value::Int(i), internals::types::Types::Float) => Value::Float(i as f32),
+            (Value::Int(i), internals::types::Types::Double) => Value::Double(i as f64),
+            (Value::Double(d), internals::types::Types::Float) => Value::Float(d as f32),
+            (Value::Float(f), internals::types::Types::Double) => Value::Double(f as f64),
+            (Value::Int(i), internals::types::Types::Sized(kind)) => {
+                Value::Sized(*kind, kind.wrap(i as i128))
+            }
+            (Value::Sized(_, i), internals::types::Types::Sized(kind)) => {
+                Value::Sized(*kind, kind.wrap(i))
+            }
+            (Value::Sized(_, i), internals::types::Types::Int) => Value::Int(i as i64),
+            (Value::Sized(_, i), internals::types::Types::Float) => Value::Float(i as f32),
+            (Value::Sized(_, i), internals::types::Types::Double) => Value::Double(i as f64),
+            (
+                Value::Array(array),
+                internals::types::Types::Array(element, _)
+                | internals::types::Types::Slice(element),
+            ) => {
+                let elements: Vec<Value> = array
+                    .borrow()
+                    .iter()
+                    .map(|value| value.clone().coerce(element))
+                    .collect();
+
+                *array.borrow_mut() = elements;
+                Value::Array(array)
+            }
+            (Value::Tuple(elements), internals::types::Types::Tuple(types))
+                if elements.len() == types.len() =>
+            {
+                Value::Tuple(
+                    elements
+                        .into_iter()
+                        .zip(types)
+                        .map(|(value, r#type)| value.coerce(r#type))
+                        .collect(),
+                )
+            }
+            (Value::Null, internals::types::Types::Optional(_)) => Value::Null,
+            (value @ Value::Error(_), internals::types::Types::Result(_)) => value,
+            (value, internals::types::Types::Result(r#type)) => value.coerce(r#type),
+            (value, internals::types::Types::Optional(r#type)) => value.coerce(r#type),
+            (value, internals::types::Types::Alias(_, r#type)) => value.coerce(r#type),
+            (value, _) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Void => write!(f, "void"),
+            Value::Null => write!(f, "null"),
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Sized(_, i) => write!(f, "{}", i),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Double(x) => write!(f, "{}", x),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Char(c) => write!(f, "{}", c),
+            Value::String(s) => write!(f, "{}", s),
+            Value::Array(array) => {
+                let elements: Vec<String> = array
+                    .borrow()
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect();
+                write!(f, "[{}]", elements.join(", "))
+            }
+            Value::Tuple(elements) => {
+                let elements: Vec<String> =
+                    elements.iter().map(|value| value.to_string()).collect();
+                write!(f, "({})", elements.join(", "))
+            }
+            Value::Range {
+                from,
+                to,
+                inclusive: false,
+                ..
+            } => write!(f, "{}..{}", from, to),
+            Value::Range {
+                from,
+                to,
+                inclusive: true,
+                ..
+            } => write!(f, "{}..={}", from, to),
+            Value::Object(object) => {
+                let object = object.borrow();
+                let fields: Vec<String> = object
+                    .fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                if fields.is_empty() {
+                    write!(f, "{} {{}}", object.r#type)
+                } else {
+                    write!(f, "{} {{ {} }}", object.r#type, fields.join(", "))
+                }
+            }
+            Value::Function(closure) => write!(f, "{}", closure.get_type()),
+            Value::Error(failure) => write!(f, "{}", failure.message),
+        }
+    }
+}
+
+/// Replaces the escape sequences of a char or string literal.
+fn unescape(input: &str) -> String {
+    let mut output: String = String::new();
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('t') => output.push('\t'),
+            Some('r') => output.push('\r'),
+            Some('b') => output.push('\u{8}'),
+            Some('f') => output.push('\u{c}'),
+            Some(other) => output.push(other),
+            None => output.push('\\'),
+        }
+    }
+
+    output
+}
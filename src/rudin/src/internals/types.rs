@@ -1,18 +1,396 @@
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum Types {
     Void,
     Int,
+    /// An integer of a fixed width -> i8, u64, usize
+    Sized(IntKind),
     Float,
     Double,
     String,
     Bool,
     Char,
-    Array(Vec<Types>),
+    /// The value thrown by a function that fails, with its message
+    Error,
+    Array(Box<Types>, usize),
+    Slice(Box<Types>),
     Tuple(Vec<Types>),
     Vector(Vec<Types>),
+    /// The integers between two bounds, with the type of both -> 0..10 is a range of int
+    Range(Box<Types>),
     Struct,
     Class,
-    Function(std::collections::HashMap<String, Types>),
+    /// The types of the parameters and the return type -> func(int, int) -> int
+    Function(Vec<Types>, Box<Types>),
     Custom(String),
+    /// Another name for a type, it's written with its name but used as the type -> Meters for double
+    Alias(String, Box<Types>),
+    /// A generic type with its type arguments -> Box<int>
+    Generic(String, Vec<Types>),
+    /// A type whose values can also be null -> int?
+    Optional(Box<Types>),
+    /// The type of the null literal, it fits any optional type
+    Null,
+    /// What a function that throws gives back, its value or an error -> int throws
+    Result(Box<Types>),
     Unknown,
 }
+
+/// The width and the sign of a sized integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntKind {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+}
+
+impl IntKind {
+    /// The number of bits of its values, usize is as wide as the addresses of the host.
+    pub fn bits(&self) -> u32 {
+        match self {
+            IntKind::I8 | IntKind::U8 => 8,
+            IntKind::I16 | IntKind::U16 => 16,
+            IntKind::I32 | IntKind::U32 => 32,
+            IntKind::I64 | IntKind::U64 => 64,
+            IntKind::Usize => usize::BITS,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntKind::I8 | IntKind::I16 | IntKind::I32 | IntKind::I64
+        )
+    }
+
+    /// The smallest and the largest of its values.
+    pub fn range(&self) -> (i128, i128) {
+        let bits: u32 = self.bits();
+
+        if self.is_signed() {
+            (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+        } else {
+            (0, (1 << bits) - 1)
+        }
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        let (min, max) = self.range();
+        min <= value && value <= max
+    }
+
+    /// The value with the bits that don't fit dropped, like a cast does -> 300 is 44 as a u8
+    pub fn wrap(&self, value: i128) -> i128 {
+        let bits: u32 = self.bits();
+        let truncated: i128 = value & ((1 << bits) - 1);
+
+        if self.is_signed() && truncated >= 1 << (bits - 1) {
+            truncated - (1 << bits)
+        } else {
+            truncated
+        }
+    }
+}
+
+impl std::fmt::Display for IntKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name: &str = match self {
+            IntKind::I8 => "i8",
+            IntKind::I16 => "i16",
+            IntKind::I32 => "i32",
+            IntKind::I64 => "i64",
+            IntKind::U8 => "u8",
+            IntKind::U16 => "u16",
+            IntKind::U32 => "u32",
+            IntKind::U64 => "u64",
+            IntKind::Usize => "usize",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// How a value of a type becomes a value of another type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conversion {
+    /// Both types are the same
+    Identity,
+    /// Done without asking, numbers are only widened -> int to double
+    Implicit,
+    /// Needs a cast, the value can lose information -> double as int
+    Explicit,
+    /// No cast can do it -> str as int
+    Invalid,
+}
+
+/// The traits every type gets from the language itself, usable as bounds of type parameters.
+pub const BUILTIN_TRAITS: &[&str] = &["Numeric", "Comparable", "Equatable", "Printable"];
+
+/// An alias is the same type as the one it names.
+impl PartialEq for Types {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Types::Alias(_, r#type), other) | (other, Types::Alias(_, r#type)) => {
+                r#type.as_ref() == other
+            }
+            (Types::Array(left, left_size), Types::Array(right, right_size)) => {
+                left == right && left_size == right_size
+            }
+            (Types::Slice(left), Types::Slice(right))
+            | (Types::Optional(left), Types::Optional(right))
+            | (Types::Result(left), Types::Result(right)) => left == right,
+            (Types::Tuple(left), Types::Tuple(right))
+            | (Types::Vector(left), Types::Vector(right)) => left == right,
+            (Types::Function(left, left_type), Types::Function(right, right_type)) => {
+                left == right && left_type == right_type
+            }
+            (Types::Sized(left), Types::Sized(right)) => left == right,
+            (Types::Custom(left), Types::Custom(right)) => left == right,
+            (Types::Generic(left, left_arguments), Types::Generic(right, right_arguments)) => {
+                left == right && left_arguments == right_arguments
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Types {
+    /// The type with every alias in it replaced by the type it names -> double[] for Meters[]
+    pub fn unaliased(&self) -> Types {
+        match self {
+            Types::Alias(_, r#type) => r#type.unaliased(),
+            Types::Array(element, size) => Types::Array(Box::new(element.unaliased()), *size),
+            Types::Slice(element) => Types::Slice(Box::new(element.unaliased())),
+            Types::Optional(r#type) => Types::Optional(Box::new(r#type.unaliased())),
+            Types::Result(r#type) => Types::Result(Box::new(r#type.unaliased())),
+            Types::Tuple(types) => Types::Tuple(types.iter().map(Types::unaliased).collect()),
+            Types::Vector(types) => Types::Vector(types.iter().map(Types::unaliased).collect()),
+            Types::Generic(name, arguments) => Types::Generic(
+                name.clone(),
+                arguments.iter().map(Types::unaliased).collect(),
+            ),
+            Types::Function(params, r#type) => Types::Function(
+                params.iter().map(Types::unaliased).collect(),
+                Box::new(r#type.unaliased()),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns false if any part of the type is still unknown.
+    pub fn is_known(&self) -> bool {
+        match self {
+            Types::Unknown => false,
+            Types::Array(element, _)
+            | Types::Slice(element)
+            | Types::Optional(element)
+            | Types::Result(element)
+            | Types::Alias(_, element) => element.is_known(),
+            Types::Tuple(types) | Types::Vector(types) | Types::Generic(_, types) => {
+                types.iter().all(Types::is_known)
+            }
+            Types::Function(params, r#type) => {
+                params.iter().all(Types::is_known) && r#type.is_known()
+            }
+            _ => true,
+        }
+    }
+
+    /// Checks if a value of the type can be null.
+    pub fn is_nullable(&self) -> bool {
+        matches!(self.unaliased(), Types::Optional(_) | Types::Null)
+    }
+
+    /// The type without its null -> int for int?
+    pub fn non_null(&self) -> Types {
+        match self {
+            Types::Optional(r#type) => *r#type.clone(),
+            _ => self.clone(),
+        }
+    }
+
+    /// The optional version of the type, an optional type stays the same -> int? for int
+    pub fn optional(&self) -> Types {
+        match self {
+            Types::Optional(_) | Types::Null | Types::Void | Types::Unknown => self.clone(),
+            _ => Types::Optional(Box::new(self.clone())),
+        }
+    }
+
+    /// The type of the value when nothing is thrown -> int for int throws
+    pub fn success(&self) -> Types {
+        match self {
+            Types::Result(r#type) => *r#type.clone(),
+            _ => self.clone(),
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || matches!(self.unaliased(), Types::Float | Types::Double)
+    }
+
+    /// Checks if the type is int or a sized integer type.
+    pub fn is_integer(&self) -> bool {
+        self.integer_range().is_some()
+    }
+
+    /// The smallest and the largest value of an integer type -> (0, 255) for u8
+    pub fn integer_range(&self) -> Option<(i128, i128)> {
+        match self.unaliased() {
+            Types::Int => Some((i64::MIN as i128, i64::MAX as i128)),
+            Types::Sized(kind) => Some(kind.range()),
+            _ => None,
+        }
+    }
+
+    /// Checks if every value of an integer type is also a value of another one -> u8 in i16
+    fn fits_in(&self, other: &Types) -> bool {
+        match (self.integer_range(), other.integer_range()) {
+            (Some((min, max)), Some((other_min, other_max))) => {
+                other_min <= min && max <= other_max
+            }
+            _ => false,
+        }
+    }
+
+    /// The type two numbers are converted to when they meet -> int < float < double
+    ///
+    /// An int meets a sized integer as that type and two sized integers meet as the one that holds
+    /// the values of both -> u8 + 1 is a u8, u8 + i32 is an i32, i32 + u32 has no common type
+    pub fn common_numeric(&self, other: &Types) -> Option<Types> {
+        match (self, other) {
+            (Types::Int, Types::Int) => Some(Types::Int),
+            (Types::Int, Types::Sized(kind)) | (Types::Sized(kind), Types::Int) => {
+                Some(Types::Sized(*kind))
+            }
+            (Types::Sized(_), Types::Sized(_)) if self.fits_in(other) => Some(other.clone()),
+            (Types::Sized(_), Types::Sized(_)) if other.fits_in(self) => Some(self.clone()),
+            (Types::Sized(_), Types::Sized(_)) => None,
+            (Types::Double, right) if right.is_numeric() => Some(Types::Double),
+            (left, Types::Double) if left.is_numeric() => Some(Types::Double),
+            (left, right) if left.is_numeric() && right.is_numeric() => Some(Types::Float),
+            _ => None,
+        }
+    }
+
+    /// Checks if the type takes part in the conversions between primitive types.
+    pub fn is_primitive(&self) -> bool {
+        self.is_integer()
+            || matches!(
+                self.unaliased(),
+                Types::Float | Types::Double | Types::Char | Types::Bool | Types::String
+            )
+    }
+
+    /// The conversion matrix of the primitive types, any other type only converts to itself.
+    ///
+    /// | from \ to | int      | float    | double   | char     | bool     | str      |
+    /// |-----------|----------|----------|----------|----------|----------|----------|
+    /// | int       | identity | implicit | implicit | explicit | explicit | explicit |
+    /// | float     | explicit | identity | implicit | invalid  | invalid  | explicit |
+    /// | double    | explicit | explicit | identity | invalid  | invalid  | explicit |
+    /// | char      | explicit | invalid  | invalid  | identity | invalid  | explicit |
+    /// | bool      | explicit | invalid  | invalid  | invalid  | identity | explicit |
+    /// | str       | invalid  | invalid  | invalid  | invalid  | invalid  | identity |
+    ///
+    /// The sized integers convert like int, between two integer types the conversion is implicit
+    /// when the target holds every value of the source -> u8 to i16, and explicit otherwise.
+    pub fn conversion(&self, to: &Types) -> Conversion {
+        if self == to {
+            return Conversion::Identity;
+        }
+
+        match (self.unaliased(), to.unaliased()) {
+            (from, to) if from.is_integer() && to.is_integer() => {
+                if from.fits_in(&to) {
+                    Conversion::Implicit
+                } else {
+                    Conversion::Explicit
+                }
+            }
+            (from, Types::Float | Types::Double) if from.is_integer() => Conversion::Implicit,
+            (Types::Float, Types::Double) => Conversion::Implicit,
+            (Types::Float | Types::Double, to) if to.is_integer() => Conversion::Explicit,
+            (Types::Double, Types::Float) => Conversion::Explicit,
+            (from, Types::Char | Types::Bool) if from.is_integer() => Conversion::Explicit,
+            (Types::Char | Types::Bool, to) if to.is_integer() => Conversion::Explicit,
+            (from, Types::String) if from.is_primitive() => Conversion::Explicit,
+            _ => Conversion::Invalid,
+        }
+    }
+
+    /// Checks if the type satisfies one of the built-in traits.
+    pub fn implements(&self, r#trait: &str) -> bool {
+        match r#trait {
+            "Numeric" => self.is_numeric(),
+            "Comparable" => {
+                self.is_numeric() || matches!(self.unaliased(), Types::Char | Types::String)
+            }
+            "Equatable" | "Printable" => *self != Types::Void,
+            _ => false,
+        }
+    }
+}
+
+/// The name of a type as it's written in the source -> int[4], Box<str>
+impl std::fmt::Display for Types {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let list = |types: &Vec<Types>| -> String {
+            types
+                .iter()
+                .map(|r#type| r#type.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        match self {
+            Types::Void => write!(f, "void"),
+            Types::Int => write!(f, "int"),
+            Types::Sized(kind) => write!(f, "{}", kind),
+            Types::Float => write!(f, "float"),
+            Types::Double => write!(f, "double"),
+            Types::String => write!(f, "str"),
+            Types::Bool => write!(f, "bool"),
+            Types::Char => write!(f, "char"),
+            Types::Error => write!(f, "error"),
+            Types::Array(..) | Types::Slice(_) => {
+                // The outermost dimension is written first -> int[2][3]
+                let mut dimensions: String = String::new();
+                let mut element: &Types = self;
+
+                loop {
+                    match element {
+                        Types::Array(inner, size) => {
+                            dimensions.push_str(&format!("[{}]", size));
+                            element = inner;
+                        }
+                        Types::Slice(inner) => {
+                            dimensions.push_str("[]");
+                            element = inner;
+                        }
+                        _ => break,
+                    }
+                }
+
+                write!(f, "{}{}", element, dimensions)
+            }
+            Types::Tuple(types) => write!(f, "({})", list(types)),
+            Types::Vector(types) => write!(f, "vec<{}>", list(types)),
+            Types::Range(_) => write!(f, "range"),
+            Types::Struct => write!(f, "struct"),
+            Types::Class => write!(f, "class"),
+            Types::Function(params, r#type) => write!(f, "func({}) -> {}", list(params), r#type),
+            Types::Custom(name) | Types::Alias(name, _) => write!(f, "{}", name),
+            Types::Generic(name, arguments) => write!(f, "{}<{}>", name, list(arguments)),
+            Types::Optional(r#type) => write!(f, "{}?", r#type),
+            Types::Null => write!(f, "null"),
+            Types::Result(r#type) => write!(f, "{} throws", r#type),
+            Types::Unknown => write!(f, "unknown"),
+        }
+    }
+}
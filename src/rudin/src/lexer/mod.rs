@@ -72,17 +72,10 @@ impl Lexer {
                 }
             }
 
-            let mut index: usize = 0;
-
-            for token in self.tokens.clone() {
-                if token.kind == tokens::TokenKind::Whitespace
-                    || token.kind == tokens::TokenKind::NewLine
-                {
-                    self.tokens.remove(index);
-                }
-
-                index += 1;
-            }
+            self.tokens.retain(|token| {
+                token.kind != tokens::TokenKind::Whitespace
+                    && token.kind != tokens::TokenKind::NewLine
+            });
         }
 
         // Insert EOF token at the end of the tokens vector
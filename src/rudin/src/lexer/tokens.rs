@@ -46,6 +46,15 @@ pub enum TokenKind {
     #[token(".", lexer::word_callback)]
     Dot,
 
+    #[token("...", lexer::word_callback)]
+    Ellipsis,
+
+    #[token("..", lexer::word_callback)]
+    DotDot,
+
+    #[token("..=", lexer::word_callback)]
+    DotDotEq,
+
     #[token(",", lexer::word_callback)]
     Comma,
 
@@ -55,6 +64,12 @@ pub enum TokenKind {
     #[token(":", lexer::word_callback)]
     Colon,
 
+    #[token("?", lexer::word_callback)]
+    Question,
+
+    #[token("?.", lexer::word_callback)]
+    QuestionDot,
+
     // Declaration keywords
     #[token("func", lexer::word_callback)]
     KwFunc,
@@ -77,6 +92,12 @@ pub enum TokenKind {
     #[token("trait", lexer::word_callback)]
     KwTrait,
 
+    #[token("type", lexer::word_callback)]
+    KwType,
+
+    #[token("static", lexer::word_callback)]
+    KwStatic,
+
     // Visibility modifiers keywords
     #[token("pub", lexer::word_callback)]
     KwPub,
@@ -107,6 +128,9 @@ pub enum TokenKind {
     #[token("for", lexer::word_callback)]
     KwFor,
 
+    #[token("in", lexer::word_callback)]
+    KwIn,
+
     // Control flow keywords
     #[token("return", lexer::word_callback)]
     KwReturn,
@@ -117,10 +141,29 @@ pub enum TokenKind {
     #[token("continue", lexer::word_callback)]
     KwContinue,
 
+    // Error handling keywords
+    #[token("try", lexer::word_callback)]
+    KwTry,
+
+    #[token("catch", lexer::word_callback)]
+    KwCatch,
+
+    #[token("throw", lexer::word_callback)]
+    KwThrow,
+
+    #[token("throws", lexer::word_callback)]
+    KwThrows,
+
+    #[token("defer", lexer::word_callback)]
+    KwDefer,
+
     // Special keywords
     #[token("use", lexer::word_callback)]
     KwUse,
 
+    #[token("as", lexer::word_callback)]
+    KwAs,
+
     #[token("over", lexer::word_callback)]
     KwOver,
 
@@ -143,6 +186,33 @@ pub enum TokenKind {
     #[token("int", lexer::word_callback)]
     TyInt,
 
+    #[token("i8", lexer::word_callback)]
+    TyI8,
+
+    #[token("i16", lexer::word_callback)]
+    TyI16,
+
+    #[token("i32", lexer::word_callback)]
+    TyI32,
+
+    #[token("i64", lexer::word_callback)]
+    TyI64,
+
+    #[token("u8", lexer::word_callback)]
+    TyU8,
+
+    #[token("u16", lexer::word_callback)]
+    TyU16,
+
+    #[token("u32", lexer::word_callback)]
+    TyU32,
+
+    #[token("u64", lexer::word_callback)]
+    TyU64,
+
+    #[token("usize", lexer::word_callback)]
+    TyUsize,
+
     #[token("float", lexer::word_callback)]
     TyFloat,
 
@@ -174,6 +244,25 @@ pub enum TokenKind {
     #[token("%", lexer::word_callback)]
     OpMod,
 
+    // Bitwise operators
+    #[token("&", lexer::word_callback)]
+    OpBitAnd,
+
+    #[token("|", lexer::word_callback)]
+    OpBitOr,
+
+    #[token("^", lexer::word_callback)]
+    OpBitXor,
+
+    #[token("~", lexer::word_callback)]
+    OpBitNot,
+
+    #[token("<<", lexer::word_callback)]
+    OpShl,
+
+    #[token(">>", lexer::word_callback)]
+    OpShr,
+
     // Logical operators
     #[token("==", lexer::word_callback)]
     OpEq,
@@ -224,6 +313,21 @@ pub enum TokenKind {
     #[token("%=", lexer::word_callback)]
     OpModAssign,
 
+    #[token("&=", lexer::word_callback)]
+    OpBitAndAssign,
+
+    #[token("|=", lexer::word_callback)]
+    OpBitOrAssign,
+
+    #[token("^=", lexer::word_callback)]
+    OpBitXorAssign,
+
+    #[token("<<=", lexer::word_callback)]
+    OpShlAssign,
+
+    #[token(">>=", lexer::word_callback)]
+    OpShrAssign,
+
     // Special operators
     #[token("++", lexer::word_callback)]
     OpInc,
@@ -234,14 +338,24 @@ pub enum TokenKind {
     #[token("->", lexer::word_callback)]
     OpArrow,
 
+    #[token("=>", lexer::word_callback)]
+    OpFatArrow,
+
+    #[token("??", lexer::word_callback)]
+    OpCoalesce,
+
     // Literals
     #[regex("[a-zA-Z_][a-zA-Z0-9_]*", lexer::word_callback)]
     Identifier,
 
-    #[regex(r"-?(?:0|[1-9]\d*)(?:\.\d+)?(?:[eE][+-]?\d+)?", lexer::word_callback)]
+    /// The name of a loop, a char literal is longer -> 'outer
+    #[regex("'[a-zA-Z_][a-zA-Z0-9_]*", lexer::word_callback)]
+    Label,
+
+    #[regex(r"(?:0|[1-9]\d*)(?:\.\d+)?(?:[eE][+-]?\d+)?", lexer::word_callback)]
     Number,
 
-    #[regex(r#"'([^'\\]|\\['\\bnfrt]|u[a-fA-F0-9]{4})*'"#, lexer::word_callback)]
+    #[regex(r#"'([^'\\]|\\['\\bnfrt]|u[a-fA-F0-9]{4})'"#, lexer::word_callback)]
     CharLiteral,
 
     #[regex(r#""([^"\\]|\\["\\bnfrt]|u[a-fA-F0-9]{4})*""#, lexer::word_callback)]
@@ -252,6 +366,9 @@ pub enum TokenKind {
 
     #[token("false", lexer::word_callback)]
     False,
+
+    #[token("null", lexer::word_callback)]
+    Null,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -261,6 +378,61 @@ pub struct Token {
     pub value: String,
 }
 
+impl TokenKind {
+    /// Checks if the operator only works on the bits of integers -> '&', '|', '^', '~', "<<", ">>"
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::OpBitAnd
+                | TokenKind::OpBitOr
+                | TokenKind::OpBitXor
+                | TokenKind::OpBitNot
+                | TokenKind::OpShl
+                | TokenKind::OpShr
+                | TokenKind::OpBitAndAssign
+                | TokenKind::OpBitOrAssign
+                | TokenKind::OpBitXorAssign
+                | TokenKind::OpShlAssign
+                | TokenKind::OpShrAssign
+        )
+    }
+
+    /// The operator a compound assignment applies to its target -> '+' for "+=" and "++"
+    pub fn binary_operator(&self) -> Option<TokenKind> {
+        match self {
+            TokenKind::OpAddAssign | TokenKind::OpInc => Some(TokenKind::OpAdd),
+            TokenKind::OpSubAssign | TokenKind::OpDec => Some(TokenKind::OpSub),
+            TokenKind::OpMulAssign => Some(TokenKind::OpMul),
+            TokenKind::OpDivAssign => Some(TokenKind::OpDiv),
+            TokenKind::OpModAssign => Some(TokenKind::OpMod),
+            TokenKind::OpBitAndAssign => Some(TokenKind::OpBitAnd),
+            TokenKind::OpBitOrAssign => Some(TokenKind::OpBitOr),
+            TokenKind::OpBitXorAssign => Some(TokenKind::OpBitXor),
+            TokenKind::OpShlAssign => Some(TokenKind::OpShl),
+            TokenKind::OpShrAssign => Some(TokenKind::OpShr),
+            _ => None,
+        }
+    }
+
+    /// The name of the method a type overloads the operator with, compound assignments and "!="
+    /// included -> "+" for "+=", "==" for "!="
+    pub fn overload(&self) -> Option<&'static str> {
+        match self {
+            TokenKind::OpAdd | TokenKind::OpAddAssign => Some("+"),
+            TokenKind::OpSub | TokenKind::OpSubAssign => Some("-"),
+            TokenKind::OpMul | TokenKind::OpMulAssign => Some("*"),
+            TokenKind::OpDiv | TokenKind::OpDivAssign => Some("/"),
+            TokenKind::OpMod | TokenKind::OpModAssign => Some("%"),
+            TokenKind::OpEq | TokenKind::OpNeq => Some("=="),
+            TokenKind::OpLt => Some("<"),
+            TokenKind::OpGt => Some(">"),
+            TokenKind::OpLe => Some("<="),
+            TokenKind::OpGe => Some(">="),
+            _ => None,
+        }
+    }
+}
+
 impl Token {
     pub fn new(kind: TokenKind, position: internals::Position, value: String) -> Self {
         Self {
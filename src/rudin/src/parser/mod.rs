@@ -1,3 +1,4 @@
+pub mod operators;
 pub mod statements;
 
 use crate::*;
@@ -5,6 +6,10 @@ use crate::*;
 pub struct Parser {
     tokens: std::iter::Peekable<std::vec::IntoIter<lexer::tokens::Token>>,
     current_token: lexer::tokens::Token,
+    // The second '>' of a ">>" that closed nested type arguments -> Box<Box<int>>
+    split_token: Option<lexer::tokens::Token>,
+    // False while parsing a condition, where '{' starts the block -> if value { ... }
+    struct_literals: bool,
     pub output: Vec<handling::Message>,
     pub ast: statements::Statement,
 }
@@ -22,6 +27,8 @@ impl Parser {
         Self {
             tokens,
             current_token,
+            split_token: None,
+            struct_literals: true,
             output: Vec::new(),
             ast,
         }
@@ -30,10 +37,34 @@ impl Parser {
     /// Parses the input tokens and constructs the AST.
     pub fn parse(&mut self) {
         while self.current_token.kind != lexer::tokens::TokenKind::Eof {
+            let visibility: Option<parser::statements::Visibility> = self.parse_visibility();
+
             let statement: Option<parser::statements::Statement> = match self.current().kind {
-                lexer::tokens::TokenKind::KwVar => self.parse_var_statement(),
-                lexer::tokens::TokenKind::KwConst => self.parse_const_statement(),
-                lexer::tokens::TokenKind::KwFunc => self.parse_function_statement(),
+                lexer::tokens::TokenKind::KwVar => {
+                    self.parse_var_statement(visibility.unwrap_or_default())
+                }
+                lexer::tokens::TokenKind::KwConst => {
+                    self.parse_const_statement(visibility.unwrap_or_default())
+                }
+                lexer::tokens::TokenKind::KwFunc => {
+                    self.parse_function_statement(visibility.unwrap_or_default())
+                }
+                lexer::tokens::TokenKind::KwStruct | lexer::tokens::TokenKind::KwClass => {
+                    self.parse_type_statement(visibility.unwrap_or_default())
+                }
+                lexer::tokens::TokenKind::KwType => {
+                    self.parse_type_alias_statement(visibility.unwrap_or_default())
+                }
+                lexer::tokens::TokenKind::KwUse if visibility.is_none() => {
+                    self.parse_use_statement()
+                }
+                _ if visibility.is_some() => {
+                    self.output.push(handling::Message::expected_error(
+                        "a declaration",
+                        self.current(),
+                    ));
+                    None
+                }
                 _ => {
                     self.output
                         .push(handling::Message::unexpected_error(self.current()));
@@ -51,13 +82,58 @@ impl Parser {
         }
     }
 
+    /// Parses a visibility modifier if there is one -> pub, priv or prot
+    fn parse_visibility(&mut self) -> Option<parser::statements::Visibility> {
+        let visibility: parser::statements::Visibility = match self.current_kind() {
+            lexer::tokens::TokenKind::KwPub => parser::statements::Visibility::Public,
+            lexer::tokens::TokenKind::KwPriv => parser::statements::Visibility::Private,
+            lexer::tokens::TokenKind::KwProt => parser::statements::Visibility::Protected,
+            _ => {
+                return None;
+            }
+        };
+        self.advance();
+
+        Some(visibility)
+    }
+
+    /// Parse a module import -> use module;
+    fn parse_use_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "use" <- Token
+        let use_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let module: String = match self.current().kind {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "a module name",
+                    self.current(),
+                ));
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::Use {
+            start: use_token.position,
+            module,
+        })
+    }
+
     /// Pushes a statement onto the AST.
     fn push_statement(&mut self, statement: parser::statements::Statement) {
-        match &mut self.ast {
-            parser::statements::Statement::Program { body, .. } => {
-                body.push(statement);
-            }
-            _ => {}
+        if let parser::statements::Statement::Program { body, .. } = &mut self.ast {
+            body.push(statement);
         }
     }
 
@@ -73,6 +149,11 @@ impl Parser {
 
     /// Advances the parser to the next token.
     fn advance(&mut self) {
+        if let Some(token) = self.split_token.take() {
+            self.current_token = token;
+            return;
+        }
+
         match self.current().kind {
             lexer::tokens::TokenKind::Eof => {}
             _ => self.current_token = self.tokens.next().unwrap(),
@@ -81,6 +162,10 @@ impl Parser {
 
     /// Returns the kind of the next token without consuming it.
     fn peek_kind(&mut self) -> &lexer::tokens::TokenKind {
+        if let Some(token) = &self.split_token {
+            return &token.kind;
+        }
+
         match self.tokens.peek() {
             Some(token) => &token.kind,
             None => &lexer::tokens::TokenKind::Eof,
@@ -92,42 +177,397 @@ impl Parser {
         self.current_kind().eq(expected)
     }
 
-    /// Transforms the current token into a type.
+    /// Transforms the current token into a type, followed by any array or optional suffix -> int[4], int[], int?
     fn get_type(&mut self) -> Option<internals::types::Types> {
-        match self.current_kind() {
-            lexer::tokens::TokenKind::TyInt => Some(internals::types::Types::Int),
-            lexer::tokens::TokenKind::TyFloat => Some(internals::types::Types::Float),
-            lexer::tokens::TokenKind::TyDouble => Some(internals::types::Types::Double),
-            lexer::tokens::TokenKind::TyBool => Some(internals::types::Types::Bool),
-            lexer::tokens::TokenKind::TyChar => Some(internals::types::Types::Char),
-            lexer::tokens::TokenKind::TyString => Some(internals::types::Types::String),
-            lexer::tokens::TokenKind::TyVoid => Some(internals::types::Types::Void),
-            lexer::tokens::TokenKind::Identifier => Some(internals::types::Types::Custom(
-                self.current().value.clone(),
-            )),
+        let mut r#type: internals::types::Types = match self.current_kind().clone() {
+            lexer::tokens::TokenKind::TyInt => internals::types::Types::Int,
+            lexer::tokens::TokenKind::TyI8 => {
+                internals::types::Types::Sized(internals::types::IntKind::I8)
+            }
+            lexer::tokens::TokenKind::TyI16 => {
+                internals::types::Types::Sized(internals::types::IntKind::I16)
+            }
+            lexer::tokens::TokenKind::TyI32 => {
+                internals::types::Types::Sized(internals::types::IntKind::I32)
+            }
+            lexer::tokens::TokenKind::TyI64 => {
+                internals::types::Types::Sized(internals::types::IntKind::I64)
+            }
+            lexer::tokens::TokenKind::TyU8 => {
+                internals::types::Types::Sized(internals::types::IntKind::U8)
+            }
+            lexer::tokens::TokenKind::TyU16 => {
+                internals::types::Types::Sized(internals::types::IntKind::U16)
+            }
+            lexer::tokens::TokenKind::TyU32 => {
+                internals::types::Types::Sized(internals::types::IntKind::U32)
+            }
+            lexer::tokens::TokenKind::TyU64 => {
+                internals::types::Types::Sized(internals::types::IntKind::U64)
+            }
+            lexer::tokens::TokenKind::TyUsize => {
+                internals::types::Types::Sized(internals::types::IntKind::Usize)
+            }
+            lexer::tokens::TokenKind::TyFloat => internals::types::Types::Float,
+            lexer::tokens::TokenKind::TyDouble => internals::types::Types::Double,
+            lexer::tokens::TokenKind::TyBool => internals::types::Types::Bool,
+            lexer::tokens::TokenKind::TyChar => internals::types::Types::Char,
+            lexer::tokens::TokenKind::TyString => internals::types::Types::String,
+            lexer::tokens::TokenKind::TyVoid => internals::types::Types::Void,
+            lexer::tokens::TokenKind::LeftParen => match self.parse_tuple_type() {
+                Some(r#type) => r#type,
+                None => {
+                    return None;
+                }
+            },
+            lexer::tokens::TokenKind::KwFunc => match self.parse_function_type() {
+                Some(r#type) => r#type,
+                None => {
+                    return None;
+                }
+            },
+            // "error" is only a type where a type is expected, it stays free as a name
+            lexer::tokens::TokenKind::Identifier if self.current().value == "error" => {
+                internals::types::Types::Error
+            }
+            // A generic type with its arguments -> Box<int>
+            lexer::tokens::TokenKind::Identifier
+                if self.peek_kind().eq(&lexer::tokens::TokenKind::OpLt) =>
+            {
+                let name: String = self.current().value.clone();
+                self.advance();
+
+                match self.parse_type_arguments() {
+                    Some(arguments) => internals::types::Types::Generic(name, arguments),
+                    None => {
+                        return None;
+                    }
+                }
+            }
+            lexer::tokens::TokenKind::Identifier => {
+                internals::types::Types::Custom(self.current().value.clone())
+            }
             _ => {
                 self.output
                     .push(handling::Message::expected_error("a type", self.current()));
                 return None;
             }
+        };
+
+        // The dimensions in the order they were written, None is a slice
+        let mut dimensions: Vec<Option<usize>> = Vec::new();
+
+        loop {
+            match self.peek_kind() {
+                lexer::tokens::TokenKind::LeftBracket => {}
+                // "?" <- Everything written before it can be null -> int[]? and int?[]
+                lexer::tokens::TokenKind::Question => {
+                    self.advance();
+                    r#type = internals::types::Types::Optional(Box::new(Self::array_type(
+                        r#type,
+                        std::mem::take(&mut dimensions),
+                    )));
+                    continue;
+                }
+                _ => break,
+            }
+
+            self.advance();
+            self.advance();
+
+            match self.current_kind() {
+                // "[]" <- A slice of any length
+                lexer::tokens::TokenKind::RightBracket => dimensions.push(None),
+                // "[size]" <- A fixed size array
+                lexer::tokens::TokenKind::Number => {
+                    let size: usize = match self.current().value.parse::<usize>() {
+                        Ok(size) => size,
+                        Err(_) => {
+                            self.output.push(handling::Message::expected_error(
+                                "an array size",
+                                self.current(),
+                            ));
+                            return None;
+                        }
+                    };
+                    self.advance();
+
+                    if let Some(message) = handling::Message::expected_or_error(
+                        lexer::tokens::TokenKind::RightBracket,
+                        "\']\'",
+                        self.current(),
+                    ) {
+                        self.output.push(message);
+                        return None;
+                    }
+
+                    dimensions.push(Some(size));
+                }
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "an array size or \']\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
         }
+
+        Some(Self::array_type(r#type, dimensions))
     }
 
-    /// Parse a function call expression -> function_identifier(arguments)
-    fn parse_function_call(&mut self) -> Option<parser::statements::Expression> {
-        // The function identifier(name)
-        let name: String = self.current().value.clone();
+    /// Wraps an element type in the dimensions written after it.
+    fn array_type(
+        mut r#type: internals::types::Types,
+        dimensions: Vec<Option<usize>>,
+    ) -> internals::types::Types {
+        // The first dimension is the outermost one -> int[2][3] has 2 rows of 3 elements
+        for dimension in dimensions.into_iter().rev() {
+            r#type = match dimension {
+                Some(size) => internals::types::Types::Array(Box::new(r#type), size),
+                None => internals::types::Types::Slice(Box::new(r#type)),
+            };
+        }
+
+        r#type
+    }
+
+    /// Parse the type of a function -> func(int, str) -> bool
+    fn parse_function_type(&mut self) -> Option<internals::types::Types> {
+        // "func"
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::LeftParen,
+            "\'(\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        let mut params: Vec<internals::types::Types> = Vec::new();
+
+        while !self.peek_expect(&lexer::tokens::TokenKind::RightParen) {
+            let param: internals::types::Types = match self.get_type() {
+                Some(t) => t,
+                None => {
+                    return None;
+                }
+            };
+            params.push(param);
+            self.advance();
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::RightParen => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \')\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+        }
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::OpArrow,
+            "\"->\"",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        let r#type: internals::types::Types = match self.parse_return_type() {
+            Some(t) => t,
+            None => {
+                return None;
+            }
+        };
+
+        Some(internals::types::Types::Function(params, Box::new(r#type)))
+    }
+
+    /// Parse the return type of a function, a function that can fail is marked -> int throws
+    fn parse_return_type(&mut self) -> Option<internals::types::Types> {
+        let r#type: internals::types::Types = self.get_type()?;
+
+        if self.peek_kind().eq(&lexer::tokens::TokenKind::KwThrows) {
+            self.advance();
+            return Some(internals::types::Types::Result(Box::new(r#type)));
+        }
+
+        Some(r#type)
+    }
+
+    /// Parse the types of the elements of a tuple -> (int, str)
+    fn parse_tuple_type(&mut self) -> Option<internals::types::Types> {
+        // '('
+        self.advance();
+
+        let mut types: Vec<internals::types::Types> = Vec::new();
+
+        loop {
+            let r#type: internals::types::Types = match self.get_type() {
+                Some(t) => t,
+                None => {
+                    return None;
+                }
+            };
+            types.push(r#type);
+            self.advance();
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::RightParen => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \')\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        Some(internals::types::Types::Tuple(types))
+    }
+
+    /// Parse the type arguments of a generic type -> <int, str>
+    fn parse_type_arguments(&mut self) -> Option<Vec<internals::types::Types>> {
+        // '<'
+        self.advance();
+
+        let mut arguments: Vec<internals::types::Types> = Vec::new();
+
+        loop {
+            let argument: internals::types::Types = match self.get_type() {
+                Some(t) => t,
+                None => {
+                    return None;
+                }
+            };
+            arguments.push(argument);
+            self.advance();
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::OpGt => break,
+                // Closes these arguments and the ones around them
+                lexer::tokens::TokenKind::OpShr => {
+                    let position: internals::Position = self.current().position.clone();
+
+                    self.split_token = Some(lexer::tokens::Token::new(
+                        lexer::tokens::TokenKind::OpGt,
+                        internals::Position::new(position.line, position.column + 1),
+                        ">".to_string(),
+                    ));
+                    self.current_token = lexer::tokens::Token::new(
+                        lexer::tokens::TokenKind::OpGt,
+                        position,
+                        ">".to_string(),
+                    );
+                    break;
+                }
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \'>\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        Some(arguments)
+    }
+
+    /// Parse the type parameters of a declaration -> <T, U: Comparable + Printable>
+    fn parse_generic_params(&mut self) -> Option<Vec<parser::statements::GenericParam>> {
+        // '<'
         self.advance();
 
+        let mut generics: Vec<parser::statements::GenericParam> = Vec::new();
+
+        loop {
+            let name: String = match self.current_kind() {
+                lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "a type parameter",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            };
+            self.advance();
+
+            // The traits the parameter is bounded by
+            let mut bounds: Vec<String> = Vec::new();
+
+            if self.peek_expect(&lexer::tokens::TokenKind::Colon) {
+                loop {
+                    self.advance();
+
+                    match self.current_kind() {
+                        lexer::tokens::TokenKind::Identifier => {
+                            bounds.push(self.current().value.clone())
+                        }
+                        _ => {
+                            self.output
+                                .push(handling::Message::expected_error("a trait", self.current()));
+                            return None;
+                        }
+                    }
+                    self.advance();
+
+                    if !self.peek_expect(&lexer::tokens::TokenKind::OpAdd) {
+                        break;
+                    }
+                }
+            }
+
+            generics.push(parser::statements::GenericParam { name, bounds });
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::OpGt => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \'>\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        Some(generics)
+    }
+
+    /// Parse the arguments of a call to an already parsed callee -> callee(arguments)
+    fn parse_function_call(
+        &mut self,
+        callee: parser::statements::Expression,
+    ) -> Option<parser::statements::Expression> {
         // '(' <- The start of the arguments
         self.advance();
 
         // A vector containing the current arguments of the function call
         let mut argument_vec: Vec<parser::statements::Expression> = Vec::new();
+        let mut starts: Vec<internals::Position> = Vec::new();
 
         // The current expression
         let mut expression: parser::statements::Expression;
 
+        // Once an argument is named, the following ones must be named too
+        let mut named: bool = false;
+
         // While doesn't reaches the ')'
         while !self.peek_expect(&lexer::tokens::TokenKind::RightParen)
             || !self.peek_expect(&lexer::tokens::TokenKind::Eof)
@@ -150,19 +590,68 @@ impl Parser {
                 continue;
             }
 
-            // Parse the expression for the current argument
-            expression = match self.parse_expression() {
-                Some(expr) => expr,
-                None => {
-                    return None;
-                }
-            };
+            let argument_start: internals::Position = self.current().position.clone();
 
-            // If after the expression, is a ',' or ')', push the current argument and advance
-            if self.peek_kind().eq(&lexer::tokens::TokenKind::Comma)
-                || self.peek_kind().eq(&lexer::tokens::TokenKind::RightParen)
+            // An argument given by name -> name: value
+            if self.peek_expect(&lexer::tokens::TokenKind::Identifier)
+                && self.peek_kind().eq(&lexer::tokens::TokenKind::Colon)
+            {
+                let name_token: lexer::tokens::Token = self.current().clone();
+                self.advance();
+                self.advance();
+
+                let value: parser::statements::Expression = match self.parse_nested_expression() {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                };
+
+                named = true;
+                expression = parser::statements::Expression::NamedArgument {
+                    start: name_token.position,
+                    name: name_token.value,
+                    value: Box::new(value),
+                };
+            } else if !named && self.peek_expect(&lexer::tokens::TokenKind::Ellipsis) {
+                // An array spread over the variadic parameter -> ...values
+                let spread_token: lexer::tokens::Token = self.current().clone();
+                self.advance();
+
+                let value: parser::statements::Expression = match self.parse_nested_expression() {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                };
+
+                expression = parser::statements::Expression::Spread {
+                    start: spread_token.position,
+                    value: Box::new(value),
+                };
+            } else if named {
+                self.output.push(handling::Message::new(
+                    handling::MessageKind::CodeError,
+                    "Positional argument after a named argument".to_string(),
+                    Some(self.current().position.clone()),
+                ));
+                return None;
+            } else {
+                // Parse the expression for the current argument
+                expression = match self.parse_nested_expression() {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                };
+            }
+
+            // If after the expression, is a ',' or ')', push the current argument and advance
+            if self.peek_kind().eq(&lexer::tokens::TokenKind::Comma)
+                || self.peek_kind().eq(&lexer::tokens::TokenKind::RightParen)
             {
                 argument_vec.push(expression);
+                starts.push(argument_start);
                 self.advance();
             } else {
                 self.advance();
@@ -175,437 +664,1404 @@ impl Parser {
         }
 
         // If the call doesn't have arguments, return a function without arguments ;)
-        if argument_vec.len() == 0 {
+        if argument_vec.is_empty() {
             return Some(parser::statements::Expression::Call {
-                name,
+                callee: Box::new(callee),
                 arguments: None,
+                starts,
             });
         }
 
         // A full call with arguments
-        return Some(parser::statements::Expression::Call {
-            name,
+        Some(parser::statements::Expression::Call {
+            callee: Box::new(callee),
             arguments: Some(Box::new(argument_vec)),
-        });
-    }
-
-    /// Parse identifiers -> function calls, push identifier value...
-    fn parse_identifier(&mut self) -> Option<parser::statements::Expression> {
-        match self.current_kind() {
-            lexer::tokens::TokenKind::Identifier => match self.peek_kind() {
-                lexer::tokens::TokenKind::LeftParen => self.parse_function_call(),
-                _ => Some(parser::statements::Expression::Identifier(
-                    self.current().value.to_owned(),
-                )),
-            },
-            _ => {
-                self.output.push(handling::Message::expected_error(
-                    "identifier",
-                    self.current(),
-                ));
-                return None;
-            }
-        }
+            starts,
+        })
     }
 
-    /// Parse the primary expression.
-    fn parse_primary_expression(&mut self) -> Option<parser::statements::Expression> {
-        let token: lexer::tokens::Token = self.current().to_owned();
+    /// Parse the name of a member after '.' or "::", the elements of a tuple are numbers -> pair.0
+    fn parse_member_name(&mut self) -> Option<String> {
+        self.advance();
 
-        match token.kind {
-            lexer::tokens::TokenKind::Identifier => self.parse_identifier(),
-            lexer::tokens::TokenKind::Number => Some(parser::statements::Expression::Literal {
-                r#type: lexer::tokens::TokenKind::Number,
-                value: self.current().value.to_owned(),
-            }),
-            lexer::tokens::TokenKind::CharLiteral => {
-                Some(parser::statements::Expression::Literal {
-                    r#type: lexer::tokens::TokenKind::CharLiteral,
-                    value: self.current().value.to_owned(),
-                })
-            }
-            lexer::tokens::TokenKind::StringLiteral => {
-                Some(parser::statements::Expression::Literal {
-                    r#type: lexer::tokens::TokenKind::StringLiteral,
-                    value: self.current().value.to_owned(),
-                })
+        match self.current_kind() {
+            // "new" is a common name for the function that builds an instance -> Point::new()
+            lexer::tokens::TokenKind::Identifier | lexer::tokens::TokenKind::KwNew => {
+                Some(self.current().value.clone())
             }
-            lexer::tokens::TokenKind::True | lexer::tokens::TokenKind::False => {
-                Some(parser::statements::Expression::Literal {
-                    r#type: lexer::tokens::TokenKind::TyBool,
-                    value: self.current().value.to_owned(),
-                })
+            lexer::tokens::TokenKind::Number
+                if self
+                    .current()
+                    .value
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '.') =>
+            {
+                Some(self.current().value.clone())
             }
-            lexer::tokens::TokenKind::Eof => {
+            _ => {
                 self.output.push(handling::Message::expected_error(
-                    "end of expression",
-                    &token,
+                    "a member name",
+                    self.current(),
                 ));
-                return None;
-            }
-            _ => {
-                self.output
-                    .push(handling::Message::expected_error("an expression", &token));
-                return None;
+                None
             }
         }
     }
 
-    /// Parse unary expressions.
-    fn parse_unary_expression(&mut self) -> Option<parser::statements::Expression> {
-        match self.current_kind() {
-            lexer::tokens::TokenKind::OpAdd => {
-                self.advance();
-                self.parse_unary_expression()
-            }
-            lexer::tokens::TokenKind::OpSub => {
-                self.advance();
-                Some(parser::statements::Expression::Unary {
-                    operator: lexer::tokens::TokenKind::OpSub,
-                    operand: Box::new(match self.parse_unary_expression() {
-                        Some(expr) => expr,
-                        None => {
-                            return None;
-                        }
-                    }),
-                })
-            }
-            lexer::tokens::TokenKind::OpNot => {
-                self.advance();
-                Some(parser::statements::Expression::Unary {
-                    operator: lexer::tokens::TokenKind::OpNot,
-                    operand: Box::new(match self.parse_unary_expression() {
-                        Some(expr) => expr,
-                        None => {
-                            return None;
-                        }
-                    }),
-                })
-            }
-            _ => self.parse_primary_expression(),
-        }
-    }
+    /// Parse the index inside brackets -> [index]
+    fn parse_array_index(&mut self) -> Option<parser::statements::Expression> {
+        // '['
+        self.advance();
 
-    /// '*', '/' or '%' <- Multiplication, division or rest expression
-    fn parse_multiplicative_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression = match self.parse_unary_expression() {
+        let index: parser::statements::Expression = match self.parse_expression() {
             Some(expr) => expr,
             None => {
                 return None;
             }
         };
+        self.advance();
 
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpMul)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpDiv)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpMod)
-        {
-            self.advance();
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::RightBracket,
+            "\']\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
 
-            let operator: lexer::tokens::Token = self.current().clone();
-            self.advance();
+        Some(index)
+    }
+
+    /// Parse an array literal -> [element, other_element]
+    fn parse_array_literal(&mut self) -> Option<parser::statements::Expression> {
+        // '['
+        self.advance();
+
+        let mut elements: Vec<parser::statements::Expression> = Vec::new();
 
-            let right: parser::statements::Expression = match self.parse_unary_expression() {
+        while !self.peek_expect(&lexer::tokens::TokenKind::RightBracket) {
+            let element: parser::statements::Expression = match self.parse_nested_expression() {
                 Some(expr) => expr,
                 None => {
                     return None;
                 }
             };
+            elements.push(element);
+            self.advance();
 
-            left = parser::statements::Expression::Binary {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+            // The end of the elements or another element
+            match self.current_kind() {
+                lexer::tokens::TokenKind::RightBracket => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \']\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
         }
 
-        return Some(left);
+        Some(parser::statements::Expression::ArrayLiteral {
+            elements: if elements.is_empty() {
+                None
+            } else {
+                Some(Box::new(elements))
+            },
+        })
     }
 
-    /// '+' or '-' <- Sum or subratction expression
-    fn parse_plus_or_subtract_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression = match self.parse_multiplicative_expression()
-        {
-            Some(expr) => expr,
-            None => {
-                return None;
+    /// Parse identifiers -> push identifier value...
+    fn parse_identifier(&mut self) -> Option<parser::statements::Expression> {
+        match self.current_kind() {
+            lexer::tokens::TokenKind::Identifier => Some(
+                parser::statements::Expression::Identifier(self.current().value.to_owned()),
+            ),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "identifier",
+                    self.current(),
+                ));
+                None
             }
-        };
+        }
+    }
 
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpAdd)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpSub)
-        {
+    /// Parse a struct literal -> Name { field: value, other_field: value }
+    fn parse_struct_literal(&mut self) -> Option<parser::statements::Expression> {
+        let name: String = self.current().value.clone();
+        self.advance();
+
+        // '{'
+        self.advance();
+
+        let mut fields: Vec<(String, parser::statements::Expression)> = Vec::new();
+
+        while !self.peek_expect(&lexer::tokens::TokenKind::RightBrace) {
+            let field: String = match self.current_kind() {
+                lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "a field name",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            };
             self.advance();
 
-            let operator = self.current().clone();
+            if let Some(message) = handling::Message::expected_or_error(
+                lexer::tokens::TokenKind::Colon,
+                "\':\'",
+                self.current(),
+            ) {
+                self.output.push(message);
+                return None;
+            }
             self.advance();
 
-            let right: parser::statements::Expression = match self.parse_multiplicative_expression()
-            {
+            let value: parser::statements::Expression = match self.parse_nested_expression() {
                 Some(expr) => expr,
                 None => {
                     return None;
                 }
             };
+            fields.push((field, value));
+            self.advance();
 
-            left = parser::statements::Expression::Binary {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+            // The end of the fields or another field
+            match self.current_kind() {
+                lexer::tokens::TokenKind::RightBrace => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \'}\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
         }
 
-        return Some(left);
+        Some(parser::statements::Expression::StructLiteral { name, fields })
     }
 
-    /// '<', '<=', '>' or '>=' <- Size expressions
-    fn parse_greater_or_smaller_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression =
-            match self.parse_plus_or_subtract_expression() {
-                Some(expr) => expr,
+    /// Parse an anonymous function -> func (x: int) -> int { ... } or func (x: int) => x * 2
+    fn parse_lambda_expression(&mut self) -> Option<parser::statements::Expression> {
+        // "func" <- Token
+        let func_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::LeftParen,
+            "\'(\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        let params: Vec<parser::statements::FuncParam> =
+            match self.parse_function_parameters_statement() {
+                Some(params) => params,
                 None => {
                     return None;
                 }
             };
+        self.advance();
 
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpLt)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpLe)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpGt)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpGe)
-        {
-            self.advance();
-
-            let operator = self.current().clone();
-            self.advance();
+        // The return type of the arrow form can be inferred from its expression
+        let r#type: internals::types::Types = match self.current_kind() {
+            lexer::tokens::TokenKind::OpArrow => {
+                self.advance();
 
-            let right: parser::statements::Expression =
-                match self.parse_plus_or_subtract_expression() {
-                    Some(expr) => expr,
+                let r#type: internals::types::Types = match self.parse_return_type() {
+                    Some(t) => t,
                     None => {
                         return None;
                     }
                 };
+                self.advance();
 
-            left = parser::statements::Expression::Logical {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
-        }
-
-        return Some(left);
-    }
+                r#type
+            }
+            _ => internals::types::Types::Unknown,
+        };
 
-    /// '==' or '!=' <- Comparision expressions
-    fn parse_comparision_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression =
-            match self.parse_greater_or_smaller_expression() {
-                Some(expr) => expr,
-                None => {
-                    return None;
+        let body: Vec<parser::statements::Statement> = match self.current_kind() {
+            lexer::tokens::TokenKind::LeftBrace if r#type != internals::types::Types::Unknown => {
+                match self.parse_block_statement() {
+                    Some(body) => body,
+                    None => {
+                        return None;
+                    }
                 }
-            };
-
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpEq)
-            || self.peek_kind().eq(&lexer::tokens::TokenKind::OpNeq)
-        {
-            self.advance();
-
-            let operator = self.current().clone();
-            self.advance();
+            }
+            lexer::tokens::TokenKind::OpFatArrow => {
+                let arrow_token: lexer::tokens::Token = self.current().clone();
+                self.advance();
 
-            let right: parser::statements::Expression =
-                match self.parse_greater_or_smaller_expression() {
+                let expression: parser::statements::Expression = match self.parse_expression() {
                     Some(expr) => expr,
                     None => {
                         return None;
                     }
                 };
 
-            left = parser::statements::Expression::Logical {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
-        }
-
-        return Some(left);
-    }
-
-    /// 'and' or '&&' <- Logical and expressions
-    fn parse_and_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression = match self.parse_comparision_expression() {
-            Some(expr) => expr,
-            None => {
+                vec![parser::statements::Statement::Return {
+                    start: arrow_token.position,
+                    expression: Some(expression),
+                }]
+            }
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "\"->\" or \"=>\"",
+                    self.current(),
+                ));
                 return None;
             }
         };
 
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpAnd) {
-            self.advance();
-
-            let operator = self.current().clone();
-            self.advance();
-
-            let right: parser::statements::Expression = match self.parse_comparision_expression() {
-                Some(expr) => expr,
-                None => {
-                    return None;
-                }
-            };
-
-            left = parser::statements::Expression::Logical {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
-        }
-
-        return Some(left);
+        Some(parser::statements::Expression::Lambda {
+            start: func_token.position,
+            params,
+            r#type,
+            body: Box::new(body),
+        })
     }
 
-    /// 'or' or '||' <- Logical or expressions
-    fn parse_or_expression(&mut self) -> Option<parser::statements::Expression> {
-        let mut left: parser::statements::Expression = match self.parse_and_expression() {
-            Some(expr) => expr,
+    /// Parse a block whose value is its trailing expression -> { var y = 2; y * 3 }
+    fn parse_block_expression(&mut self) -> Option<parser::statements::Expression> {
+        // '{'
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::LeftBrace,
+            "start of block",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        let mut body: Vec<parser::statements::Statement> = Vec::new();
+        let mut value: Option<Box<parser::statements::Expression>> = None;
+
+        while !(self
+            .current_kind()
+            .eq(&lexer::tokens::TokenKind::RightBrace)
+            || self.current_kind().eq(&lexer::tokens::TokenKind::Eof))
+        {
+            let start_token: lexer::tokens::Token = self.current().clone();
+
+            let statement: Option<parser::statements::Statement> = match self.parse_block_item() {
+                Some(Some(statement)) => Some(statement),
+                None => {
+                    return None;
+                }
+                // Anything else is the value of the block if it's the last thing in it
+                Some(None) => {
+                    let expression: parser::statements::Expression = match self.parse_expression() {
+                        Some(expr) => expr,
+                        None => {
+                            return None;
+                        }
+                    };
+
+                    match (self.peek_kind(), &expression) {
+                        (lexer::tokens::TokenKind::RightBrace, _) => {
+                            self.advance();
+                            value = Some(Box::new(expression));
+                            break;
+                        }
+                        // An if that isn't the value of the block is only run
+                        (_, parser::statements::Expression::If { .. }) => {
+                            Some(parser::statements::Statement::Expression {
+                                start: start_token.position,
+                                expression,
+                            })
+                        }
+                        (_, _) if start_token.kind == lexer::tokens::TokenKind::Identifier => {
+                            let statement: parser::statements::Statement =
+                                self.parse_simple_statement_rest(start_token, expression)?;
+                            self.advance();
+
+                            if let Some(message) = handling::Message::expected_or_error(
+                                lexer::tokens::TokenKind::Semicolon,
+                                "end of statement",
+                                self.current(),
+                            ) {
+                                self.output.push(message);
+                                return None;
+                            }
+
+                            Some(statement)
+                        }
+                        (_, _) => {
+                            self.advance();
+                            self.output.push(handling::Message::expected_error(
+                                "end of block",
+                                self.current(),
+                            ));
+                            return None;
+                        }
+                    }
+                }
+            };
+
+            match statement {
+                Some(statement) => body.push(statement),
+                None => {
+                    return None;
+                }
+            }
+
+            self.advance();
+        }
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::RightBrace,
+            "end of block",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Expression::Block {
+            body: Box::new(body),
+            value,
+            r#type: internals::types::Types::Unknown,
+        })
+    }
+
+    /// Parse an if used as a value -> if condition { 1 } elif other { 2 } else { 3 }
+    fn parse_if_expression(&mut self) -> Option<parser::statements::Expression> {
+        // "if", "elif" <- Token
+        let if_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let condition: parser::statements::Expression = match self.parse_condition() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        let body: parser::statements::Expression = match self.parse_block_expression() {
+            Some(body) => body,
+            None => {
+                return None;
+            }
+        };
+
+        let alternate: Option<Box<parser::statements::Expression>> = match self.peek_kind() {
+            lexer::tokens::TokenKind::KwElif => {
+                self.advance();
+
+                match self.parse_if_expression() {
+                    Some(branch) => Some(Box::new(branch)),
+                    None => {
+                        return None;
+                    }
+                }
+            }
+            lexer::tokens::TokenKind::KwElse => {
+                self.advance();
+                self.advance();
+
+                match self.parse_block_expression() {
+                    Some(branch) => Some(Box::new(branch)),
+                    None => {
+                        return None;
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        Some(parser::statements::Expression::If {
+            start: if_token.position,
+            condition: Box::new(condition),
+            body: Box::new(body),
+            alternate,
+        })
+    }
+
+    /// Parse the primary expression.
+    fn parse_primary_expression(&mut self) -> Option<parser::statements::Expression> {
+        let token: lexer::tokens::Token = self.current().to_owned();
+
+        match token.kind {
+            lexer::tokens::TokenKind::Identifier
+                if self.struct_literals
+                    && self.peek_kind().eq(&lexer::tokens::TokenKind::LeftBrace) =>
+            {
+                self.parse_struct_literal()
+            }
+            lexer::tokens::TokenKind::Identifier => self.parse_identifier(),
+            lexer::tokens::TokenKind::LeftBracket => self.parse_array_literal(),
+            lexer::tokens::TokenKind::KwFunc => self.parse_lambda_expression(),
+            lexer::tokens::TokenKind::KwLoop => self.parse_loop_expression(None),
+            lexer::tokens::TokenKind::Label => {
+                let label: String = self.parse_label()?;
+
+                if let Some(message) = handling::Message::expected_or_error(
+                    lexer::tokens::TokenKind::KwLoop,
+                    "\"loop\"",
+                    self.current(),
+                ) {
+                    self.output.push(message);
+                    return None;
+                }
+
+                self.parse_loop_expression(Some(label))
+            }
+            lexer::tokens::TokenKind::KwIf => self.parse_if_expression(),
+            lexer::tokens::TokenKind::LeftBrace => self.parse_block_expression(),
+            lexer::tokens::TokenKind::Number => Some(parser::statements::Expression::Literal {
+                r#type: lexer::tokens::TokenKind::Number,
+                value: self.current().value.to_owned(),
+            }),
+            lexer::tokens::TokenKind::CharLiteral => {
+                Some(parser::statements::Expression::Literal {
+                    r#type: lexer::tokens::TokenKind::CharLiteral,
+                    value: self.current().value.to_owned(),
+                })
+            }
+            lexer::tokens::TokenKind::StringLiteral => {
+                Some(parser::statements::Expression::Literal {
+                    r#type: lexer::tokens::TokenKind::StringLiteral,
+                    value: self.current().value.to_owned(),
+                })
+            }
+            lexer::tokens::TokenKind::Null => Some(parser::statements::Expression::Literal {
+                r#type: lexer::tokens::TokenKind::Null,
+                value: self.current().value.to_owned(),
+            }),
+            lexer::tokens::TokenKind::True | lexer::tokens::TokenKind::False => {
+                Some(parser::statements::Expression::Literal {
+                    r#type: lexer::tokens::TokenKind::TyBool,
+                    value: self.current().value.to_owned(),
+                })
+            }
+            lexer::tokens::TokenKind::Eof => {
+                self.output.push(handling::Message::expected_error(
+                    "end of expression",
+                    &token,
+                ));
+                None
+            }
+            _ => {
+                self.output
+                    .push(handling::Message::expected_error("an expression", &token));
+                None
+            }
+        }
+    }
+
+    /// Parse the operator that follows an operand -> object.member, object?.member, call()?, Type::member, callee(arguments), array[index], value as type
+    fn parse_postfix_operator(
+        &mut self,
+        expression: parser::statements::Expression,
+    ) -> Option<parser::statements::Expression> {
+        match self.current_kind() {
+            // The elements of nested tuples are read as a number -> pair.0.1
+            lexer::tokens::TokenKind::Dot => Some(self.parse_member_name()?.split('.').fold(
+                expression,
+                |object, member| parser::statements::Expression::Member {
+                    object: Box::new(object),
+                    member: member.to_string(),
+                },
+            )),
+            lexer::tokens::TokenKind::QuestionDot => {
+                Some(parser::statements::Expression::OptionalMember {
+                    object: Box::new(expression),
+                    member: self.parse_member_name()?,
+                })
+            }
+            lexer::tokens::TokenKind::ColonColon => {
+                Some(parser::statements::Expression::StaticMember {
+                    r#type: Box::new(expression),
+                    member: self.parse_member_name()?,
+                })
+            }
+            lexer::tokens::TokenKind::LeftParen => self.parse_function_call(expression),
+            lexer::tokens::TokenKind::Question => Some(parser::statements::Expression::Propagate {
+                start: self.current().position.clone(),
+                value: Box::new(expression),
+            }),
+            lexer::tokens::TokenKind::KwAs => {
+                // "as"
+                self.advance();
+
+                Some(parser::statements::Expression::Cast {
+                    value: Box::new(expression),
+                    r#type: self.get_type()?,
+                })
+            }
+            lexer::tokens::TokenKind::LeftBracket => {
+                let index: Box<parser::statements::Expression> =
+                    Box::new(self.parse_array_index()?);
+
+                // Indexing the result of another index -> a[i][j]
+                match expression {
+                    parser::statements::Expression::ArrayAcess(acess) => {
+                        Some(parser::statements::Expression::ArrayAcess(
+                            parser::statements::ArrayAcess::NestedAcess {
+                                acess: Box::new(acess),
+                                index,
+                            },
+                        ))
+                    }
+                    _ => Some(parser::statements::Expression::ArrayAcess(
+                        parser::statements::ArrayAcess::Acess {
+                            array: Box::new(expression),
+                            index,
+                        },
+                    )),
+                }
+            }
+            _ => {
+                self.output
+                    .push(handling::Message::unexpected_error(self.current()));
+                None
+            }
+        }
+    }
+
+    /// Parse a parenthesized expression or a tuple -> (expression), (first, second)
+    fn parse_grouping_expression(&mut self) -> Option<parser::statements::Expression> {
+        // '('
+        self.advance();
+
+        let mut expression: parser::statements::Expression = match self.parse_nested_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        if self.peek_expect(&lexer::tokens::TokenKind::Comma) {
+            let mut elements: Vec<parser::statements::Expression> = vec![expression];
+
+            while self.peek_expect(&lexer::tokens::TokenKind::Comma) {
+                self.advance();
+
+                match self.parse_nested_expression() {
+                    Some(element) => elements.push(element),
+                    None => {
+                        return None;
+                    }
+                }
+                self.advance();
+            }
+
+            expression = parser::statements::Expression::Tuple { elements };
+        }
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::RightParen,
+            "\')\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(expression)
+    }
+
+    /// Parse the start of an expression -> prefix operators, groupings and primary expressions
+    fn parse_prefix_expression(&mut self) -> Option<parser::statements::Expression> {
+        if let Some(operator) = parser::operators::prefix(self.current_kind()) {
+            self.advance();
+
+            let operand: parser::statements::Expression =
+                match self.parse_expression_with_precedence(operator.precedence) {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                };
+
+            // A negative number is a literal of its own, so the smallest int still fits
+            if let (
+                lexer::tokens::TokenKind::OpSub,
+                parser::statements::Expression::Literal {
+                    r#type: lexer::tokens::TokenKind::Number,
+                    value,
+                },
+            ) = (&operator.token, &operand)
+            {
+                return Some(parser::statements::Expression::Literal {
+                    r#type: lexer::tokens::TokenKind::Number,
+                    value: format!("-{}", value),
+                });
+            }
+
+            return Some(parser::statements::Expression::Unary {
+                operator: operator.token.clone(),
+                operand: Box::new(operand),
+            });
+        }
+
+        match self.current_kind() {
+            lexer::tokens::TokenKind::LeftParen => self.parse_grouping_expression(),
+            _ => self.parse_primary_expression(),
+        }
+    }
+
+    /// Parse an expression whose operators bind tighter than the given precedence.
+    fn parse_expression_with_precedence(
+        &mut self,
+        precedence: u8,
+    ) -> Option<parser::statements::Expression> {
+        let mut left: parser::statements::Expression = match self.parse_prefix_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+
+        while let Some(operator) = parser::operators::infix(self.peek_kind()) {
+            if operator.precedence <= precedence {
+                break;
+            }
+
+            self.advance();
+
+            left = match operator.fixity {
+                parser::operators::Fixity::Infix(associativity, node) => {
+                    self.advance();
+
+                    // A right associative operator lets the right side take the same operator
+                    let right_precedence: u8 = match associativity {
+                        parser::operators::Associativity::Left => operator.precedence,
+                        parser::operators::Associativity::Right => operator.precedence - 1,
+                    };
+
+                    let right: parser::statements::Expression =
+                        match self.parse_expression_with_precedence(right_precedence) {
+                            Some(expr) => expr,
+                            None => {
+                                return None;
+                            }
+                        };
+
+                    match node {
+                        parser::operators::InfixNode::Binary => {
+                            parser::statements::Expression::Binary {
+                                operator: operator.token.clone(),
+                                left: Box::new(left),
+                                right: Box::new(right),
+                            }
+                        }
+                        parser::operators::InfixNode::Logical => {
+                            parser::statements::Expression::Logical {
+                                operator: operator.token.clone(),
+                                left: Box::new(left),
+                                right: Box::new(right),
+                            }
+                        }
+                        parser::operators::InfixNode::Range => {
+                            parser::statements::Expression::Range {
+                                from: Box::new(left),
+                                to: Box::new(right),
+                                inclusive: operator.token == lexer::tokens::TokenKind::DotDotEq,
+                            }
+                        }
+                    }
+                }
+                _ => match self.parse_postfix_operator(left) {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                },
+            };
+        }
+
+        Some(left)
+    }
+
+    /// Parsing expressions related function
+    fn parse_expression(&mut self) -> Option<parser::statements::Expression> {
+        self.parse_expression_with_precedence(0)
+    }
+
+    /// Parse an expression with struct literals allowed or not, restoring the previous state.
+    fn parse_expression_with_struct_literals(
+        &mut self,
+        allowed: bool,
+    ) -> Option<parser::statements::Expression> {
+        let previous: bool = std::mem::replace(&mut self.struct_literals, allowed);
+        let expression: Option<parser::statements::Expression> = self.parse_expression();
+        self.struct_literals = previous;
+
+        expression
+    }
+
+    /// Parse an expression enclosed by delimiters, where a struct literal can't be a block
+    fn parse_nested_expression(&mut self) -> Option<parser::statements::Expression> {
+        self.parse_expression_with_struct_literals(true)
+    }
+
+    /// Parse the condition before the block of an if, elif or while, or the iterable of a for
+    fn parse_condition(&mut self) -> Option<parser::statements::Expression> {
+        self.parse_expression_with_struct_literals(false)
+    }
+
+    /// Parse a variable statement (declaration)
+    fn parse_var_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
+        // "var" -> token
+        let var_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        if self.peek_expect(&lexer::tokens::TokenKind::LeftParen) {
+            return self.parse_destructure_statement(visibility, var_token);
+        }
+
+        // The variable name
+        let name: String = match self.current().kind {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "an identifier",
+                    self.current(),
+                ));
+                return None;
+            }
+        };
+        self.advance();
+
+        let r#type: internals::types::Types = match self.parse_type_annotation() {
+            Some(t) => t,
+            None => {
+                return None;
+            }
+        };
+
+        match self.current().kind {
+            lexer::tokens::TokenKind::OpAssign => {
+                self.advance();
+            }
+            lexer::tokens::TokenKind::Semicolon => {
+                return Some(parser::statements::Statement::VariableDeclaration {
+                    visibility,
+                    start: var_token.position,
+                    name,
+                    r#type,
+                    value: None,
+                });
+            }
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "assignment operator or end of statement",
+                    self.current(),
+                ));
+                return None;
+            }
+        }
+
+        let value = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::VariableDeclaration {
+            visibility,
+            start: var_token.position,
+            name,
+            r#type,
+            value: Some(value),
+        })
+    }
+
+    /// Parse the declaration of the variables a tuple is split into -> var (a, b): (int, str) = pair();
+    fn parse_destructure_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+        var_token: lexer::tokens::Token,
+    ) -> Option<parser::statements::Statement> {
+        // '('
+        self.advance();
+
+        let mut names: Vec<String> = Vec::new();
+
+        loop {
+            match self.current_kind() {
+                lexer::tokens::TokenKind::Identifier => names.push(self.current().value.clone()),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "an identifier",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+            self.advance();
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::RightParen => break,
+                lexer::tokens::TokenKind::Comma => self.advance(),
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "\',\' or \')\'",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+        }
+        self.advance();
+
+        let r#type: internals::types::Types = match self.parse_type_annotation() {
+            Some(t) => t,
+            None => {
+                return None;
+            }
+        };
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::OpAssign,
+            "assignment operator",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        let value: parser::statements::Expression = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::Destructure {
+            visibility,
+            start: var_token.position,
+            names,
+            r#type,
+            value,
+        })
+    }
+
+    /// Parse the optional ": type" of a declaration, a missing one is inferred by the analyzer
+    fn parse_type_annotation(&mut self) -> Option<internals::types::Types> {
+        if *self.current_kind() != lexer::tokens::TokenKind::Colon {
+            return Some(internals::types::Types::Unknown);
+        }
+        self.advance();
+
+        let r#type: internals::types::Types = match self.get_type() {
+            Some(t) => t,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        Some(r#type)
+    }
+
+    /// Parse a constant statement (declaration)
+    fn parse_const_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
+        // "const" -> token
+        let const_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        // The variable name
+        let name: String = match self.current().kind {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "an identifier",
+                    self.current(),
+                ));
+                return None;
+            }
+        };
+        self.advance();
+
+        let r#type: internals::types::Types = match self.parse_type_annotation() {
+            Some(t) => t,
+            None => {
+                return None;
+            }
+        };
+
+        match self.current().kind {
+            lexer::tokens::TokenKind::OpAssign => {
+                self.advance();
+            }
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "assignment operator or end of statement",
+                    self.current(),
+                ));
+                return None;
+            }
+        }
+
+        let value = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::ConstantDeclaration {
+            visibility,
+            start: const_token.position,
+            name,
+            r#type,
+            value,
+        })
+    }
+
+    fn parse_return_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "return" <- Token
+        let return_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        // A void function leaves early without a value -> return;
+        if self.current().kind == lexer::tokens::TokenKind::Semicolon {
+            return Some(parser::statements::Statement::Return {
+                start: return_token.position,
+                expression: None,
+            });
+        }
+
+        let mut expression: parser::statements::Expression = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // Several values are returned as a tuple -> return quotient, remainder;
+        if self.peek_expect(&lexer::tokens::TokenKind::Comma) {
+            let mut elements: Vec<parser::statements::Expression> = vec![expression];
+
+            while self.peek_expect(&lexer::tokens::TokenKind::Comma) {
+                self.advance();
+
+                match self.parse_expression() {
+                    Some(element) => elements.push(element),
+                    None => {
+                        return None;
+                    }
+                }
+                self.advance();
+            }
+
+            expression = parser::statements::Expression::Tuple { elements };
+        }
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::Return {
+            start: return_token.position,
+            expression: Some(expression),
+        })
+    }
+
+    /// Parse a throw statement -> throw "message";
+    fn parse_throw_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "throw" <- Token
+        let throw_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let value: parser::statements::Expression = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::Throw {
+            start: throw_token.position,
+            value,
+        })
+    }
+
+    /// Parse a try statement -> try { ... } catch error { ... }
+    fn parse_try_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "try" <- Token
+        let try_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::KwCatch,
+            "\"catch\"",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        // The name of the caught error is optional -> catch { ... }
+        let name: Option<String> = match self.current_kind() {
+            lexer::tokens::TokenKind::Identifier => {
+                let name: String = self.current().value.clone();
+                self.advance();
+                Some(name)
+            }
+            _ => None,
+        };
+
+        let catch: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(catch) => Box::new(catch),
+            None => {
+                return None;
+            }
+        };
+
+        Some(parser::statements::Statement::Try {
+            start: try_token.position,
+            body: Some(body),
+            name,
+            catch: Some(catch),
+        })
+    }
+
+    /// Parse a defer statement -> defer { ... } or defer close(file);
+    fn parse_defer_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "defer" <- Token
+        let defer_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let body: Vec<parser::statements::Statement> = match self.current_kind() {
+            lexer::tokens::TokenKind::LeftBrace => match self.parse_block_statement() {
+                Some(body) => body,
+                None => {
+                    return None;
+                }
+            },
+            _ => match self.parse_identifier_statement() {
+                Some(statement) => vec![statement],
+                None => {
+                    return None;
+                }
+            },
+        };
+
+        Some(parser::statements::Statement::Defer {
+            start: defer_token.position,
+            body: Some(Box::new(body)),
+        })
+    }
+
+    /// Parse a conditional chain -> if condition { ... } elif condition { ... } else { ... }
+    fn parse_if_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "if" or "elif" <- Token
+        let if_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let condition: parser::statements::Expression = match self.parse_condition() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
+            None => {
+                return None;
+            }
+        };
+
+        // The next branch of the chain, if any, is stored as the only statement of the alternate
+        let alternate: Option<Box<Vec<parser::statements::Statement>>> = match self.peek_kind() {
+            lexer::tokens::TokenKind::KwElif => {
+                self.advance();
+
+                match self.parse_if_statement() {
+                    Some(branch) => Some(Box::new(vec![branch])),
+                    None => {
+                        return None;
+                    }
+                }
+            }
+            lexer::tokens::TokenKind::KwElse => {
+                self.advance();
+                let else_token: lexer::tokens::Token = self.current().clone();
+                self.advance();
+
+                let else_body: Box<Vec<parser::statements::Statement>> =
+                    match self.parse_block_statement() {
+                        Some(body) => Box::new(body),
+                        None => {
+                            return None;
+                        }
+                    };
+
+                Some(Box::new(vec![parser::statements::Statement::Else {
+                    start: else_token.position,
+                    body: Some(else_body),
+                }]))
+            }
+            _ => None,
+        };
+
+        match if_token.kind {
+            lexer::tokens::TokenKind::KwElif => Some(parser::statements::Statement::ElseIf {
+                start: if_token.position,
+                condition,
+                body: Some(body),
+                alternate,
+            }),
+            _ => Some(parser::statements::Statement::If {
+                start: if_token.position,
+                condition,
+                body: Some(body),
+                alternate,
+            }),
+        }
+    }
+
+    /// Parse a while statement -> while condition { ... statements ... }
+    fn parse_while_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "while" <- Token
+        let while_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let condition: parser::statements::Expression = match self.parse_condition() {
+            Some(expr) => expr,
             None => {
                 return None;
             }
         };
+        self.advance();
 
-        while self.peek_kind().eq(&lexer::tokens::TokenKind::OpOr) {
-            self.advance();
-
-            let operator = self.current().clone();
-            self.advance();
-
-            let right: parser::statements::Expression = match self.parse_and_expression() {
-                Some(expr) => expr,
-                None => {
-                    return None;
-                }
-            };
-
-            left = parser::statements::Expression::Logical {
-                operator: operator.kind,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
-        }
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
+            None => {
+                return None;
+            }
+        };
 
-        return Some(left);
+        Some(parser::statements::Statement::While {
+            start: while_token.position,
+            label: None,
+            condition,
+            body: Some(body),
+        })
     }
 
-    /// Parsing expressions related function
-    fn parse_expression(&mut self) -> Option<parser::statements::Expression> {
-        self.parse_or_expression()
-    }
+    /// Parse a loop over the items of an iterable -> for item in items { ... statements ... }
+    fn parse_for_in_statement(
+        &mut self,
+        for_token: lexer::tokens::Token,
+    ) -> Option<parser::statements::Statement> {
+        // The name of the item
+        let name: String = self.current().value.clone();
+        self.advance();
 
-    /// Parse a variable statement (declaration)
-    fn parse_var_statement(&mut self) -> Option<parser::statements::Statement> {
-        // "var" -> token
-        let var_token: lexer::tokens::Token = self.current().clone();
+        // "in"
         self.advance();
 
-        // The variable name
-        let name: String = match self.current().kind {
-            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
-            _ => {
-                self.output.push(handling::Message::expected_error(
-                    "an identifier",
-                    self.current(),
-                ));
+        let iterable: parser::statements::Expression = match self.parse_condition() {
+            Some(expr) => expr,
+            None => {
                 return None;
             }
         };
         self.advance();
 
-        if let Some(message) = handling::Message::expected_or_error(
-            lexer::tokens::TokenKind::Colon,
-            "a colon",
-            self.current(),
-        ) {
-            self.output.push(message);
-            return None;
-        }
-        self.advance();
-
-        let r#type: internals::types::Types = match self.get_type() {
-            Some(t) => t,
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
             None => {
                 return None;
             }
         };
+
+        Some(parser::statements::Statement::ForIn {
+            start: for_token.position,
+            label: None,
+            name,
+            iterable,
+            body: Some(body),
+        })
+    }
+
+    /// Parse an infinite loop statement -> loop { ... statements ... }
+    fn parse_loop_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "loop" <- Token
+        let loop_token: lexer::tokens::Token = self.current().clone();
         self.advance();
 
-        match self.current().kind {
-            lexer::tokens::TokenKind::OpAssign => {
-                self.advance();
-            }
-            lexer::tokens::TokenKind::Semicolon => {
-                return Some(parser::statements::Statement::VariableDeclaration {
-                    start: var_token.position,
-                    name,
-                    r#type,
-                    value: None,
-                });
-            }
-            _ => {
-                self.output.push(handling::Message::expected_error(
-                    "assignment operator or end of statement",
-                    self.current(),
-                ));
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
+            None => {
                 return None;
             }
-        }
+        };
 
-        let value = match self.parse_expression() {
-            Some(expr) => expr,
+        Some(parser::statements::Statement::Loop {
+            start: loop_token.position,
+            label: None,
+            body: Some(body),
+        })
+    }
+
+    /// Parse a loop used as a value -> var x = loop { ... break value; ... };
+    fn parse_loop_expression(
+        &mut self,
+        label: Option<String>,
+    ) -> Option<parser::statements::Expression> {
+        // "loop"
+        self.advance();
+
+        let body: Vec<parser::statements::Statement> = match self.parse_block_statement() {
+            Some(body) => body,
             None => {
                 return None;
             }
         };
 
+        Some(parser::statements::Expression::Loop {
+            label,
+            body: Box::new(body),
+            r#type: internals::types::Types::Unknown,
+        })
+    }
+
+    /// Parse the label of a loop and its ':' -> 'outer:
+    fn parse_label(&mut self) -> Option<String> {
+        let label: String = self.current().value.clone();
         self.advance();
 
         if let Some(message) = handling::Message::expected_or_error(
-            lexer::tokens::TokenKind::Semicolon,
-            "end of statement",
+            lexer::tokens::TokenKind::Colon,
+            "\':\'",
             self.current(),
         ) {
             self.output.push(message);
             return None;
         }
+        self.advance();
 
-        return Some(parser::statements::Statement::VariableDeclaration {
-            start: var_token.position,
-            name,
-            r#type,
-            value: Some(value),
-        });
+        Some(label)
     }
 
-    /// Parse a constant statement (declaration)
-    fn parse_const_statement(&mut self) -> Option<parser::statements::Statement> {
-        // "const" -> token
-        let const_token: lexer::tokens::Token = self.current().clone();
-        self.advance();
+    /// Parse a loop statement with a label -> 'outer: while condition { ... statements ... }
+    fn parse_labeled_statement(&mut self) -> Option<parser::statements::Statement> {
+        let name: String = self.parse_label()?;
 
-        // The variable name
-        let name: String = match self.current().kind {
-            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+        let mut statement: parser::statements::Statement = match self.current_kind() {
+            lexer::tokens::TokenKind::KwWhile => self.parse_while_statement()?,
+            lexer::tokens::TokenKind::KwLoop => self.parse_loop_statement()?,
+            lexer::tokens::TokenKind::KwFor => self.parse_for_statement()?,
             _ => {
-                self.output.push(handling::Message::expected_error(
-                    "an identifier",
-                    self.current(),
-                ));
+                self.output
+                    .push(handling::Message::expected_error("a loop", self.current()));
                 return None;
             }
         };
+
+        match &mut statement {
+            parser::statements::Statement::While { label, .. }
+            | parser::statements::Statement::Loop { label, .. }
+            | parser::statements::Statement::For { label, .. }
+            | parser::statements::Statement::ForIn { label, .. } => *label = Some(name),
+            _ => {}
+        }
+
+        Some(statement)
+    }
+
+    /// Parse a for statement -> for (initialization; condition; update) { ... statements ... }
+    fn parse_for_statement(&mut self) -> Option<parser::statements::Statement> {
+        // "for" <- Token
+        let for_token: lexer::tokens::Token = self.current().clone();
         self.advance();
 
+        if self.peek_expect(&lexer::tokens::TokenKind::Identifier)
+            && self.peek_kind().eq(&lexer::tokens::TokenKind::KwIn)
+        {
+            return self.parse_for_in_statement(for_token);
+        }
+
         if let Some(message) = handling::Message::expected_or_error(
-            lexer::tokens::TokenKind::Colon,
-            "a colon",
+            lexer::tokens::TokenKind::LeftParen,
+            "\'(\'",
             self.current(),
         ) {
             self.output.push(message);
@@ -613,69 +2069,105 @@ impl Parser {
         }
         self.advance();
 
-        let r#type: internals::types::Types = match self.get_type() {
-            Some(t) => t,
-            None => {
-                return None;
-            }
+        // The initialization, ends at its own ';'
+        let variable: Option<Box<parser::statements::Statement>> = match self.current_kind() {
+            lexer::tokens::TokenKind::Semicolon => None,
+            lexer::tokens::TokenKind::KwVar => Some(Box::new(
+                self.parse_var_statement(parser::statements::Visibility::Private)?,
+            )),
+            _ => Some(Box::new(self.parse_identifier_statement()?)),
         };
         self.advance();
 
-        match self.current().kind {
-            lexer::tokens::TokenKind::OpAssign => {
-                self.advance();
-            }
+        // The condition, an empty one loops forever
+        let condition: Option<parser::statements::Expression> = match self.current_kind() {
+            lexer::tokens::TokenKind::Semicolon => None,
             _ => {
-                self.output.push(handling::Message::expected_error(
-                    "assignment operator or end of statement",
-                    self.current(),
-                ));
-                return None;
-            }
-        }
-
-        let value = match self.parse_expression() {
-            Some(expr) => expr,
-            None => {
-                return None;
+                let condition: parser::statements::Expression = self.parse_expression()?;
+                self.advance();
+                Some(condition)
             }
         };
 
-        self.advance();
-
         if let Some(message) = handling::Message::expected_or_error(
             lexer::tokens::TokenKind::Semicolon,
-            "end of statement",
+            "\';\'",
             self.current(),
         ) {
             self.output.push(message);
             return None;
         }
+        self.advance();
 
-        return Some(parser::statements::Statement::ConstantDeclaration {
-            start: const_token.position,
-            name,
-            r#type,
-            value,
-        });
-    }
+        // The update, runs after every iteration
+        let variable_update: Option<Box<parser::statements::Statement>> = match self.current_kind()
+        {
+            lexer::tokens::TokenKind::RightParen => None,
+            _ => {
+                let update: parser::statements::Statement = self.parse_simple_statement()?;
+                self.advance();
+                Some(Box::new(update))
+            }
+        };
 
-    fn parse_return_statement(&mut self) -> Option<parser::statements::Statement> {
-        // "return" <- Token
-        let return_token: lexer::tokens::Token = self.current().clone();
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::RightParen,
+            "\')\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
         self.advance();
 
-        let expression: parser::statements::Expression = match self.parse_expression() {
-            Some(expr) => expr,
+        let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
+            Some(body) => Box::new(body),
             None => {
                 return None;
             }
         };
 
-        dbg!(expression.clone());
+        Some(parser::statements::Statement::For {
+            start: for_token.position,
+            label: None,
+            variable,
+            condition,
+            variable_update,
+            body: Some(body),
+        })
+    }
 
+    /// Parse a break or continue statement, with an optional label and break value -> break 'outer value;
+    fn parse_jump_statement(&mut self) -> Option<parser::statements::Statement> {
+        let jump_token: lexer::tokens::Token = self.current().clone();
         self.advance();
 
+        let label: Option<String> = match self.current_kind() {
+            lexer::tokens::TokenKind::Label => {
+                let label: String = self.current().value.clone();
+                self.advance();
+                Some(label)
+            }
+            _ => None,
+        };
+
+        let value: Option<parser::statements::Expression> =
+            match (&jump_token.kind, self.current_kind()) {
+                (lexer::tokens::TokenKind::KwBreak, kind)
+                    if *kind != lexer::tokens::TokenKind::Semicolon =>
+                {
+                    let value: parser::statements::Expression = match self.parse_expression() {
+                        Some(expr) => expr,
+                        None => {
+                            return None;
+                        }
+                    };
+                    self.advance();
+                    Some(value)
+                }
+                _ => None,
+            };
+
         if let Some(message) = handling::Message::expected_or_error(
             lexer::tokens::TokenKind::Semicolon,
             "end of statement",
@@ -685,14 +2177,50 @@ impl Parser {
             return None;
         }
 
-        Some(parser::statements::Statement::Return {
-            start: return_token.position,
-            expression: Some(expression),
-        })
+        match jump_token.kind {
+            lexer::tokens::TokenKind::KwBreak => Some(parser::statements::Statement::Break {
+                start: jump_token.position,
+                label,
+                value,
+            }),
+            _ => Some(parser::statements::Statement::Continue {
+                start: jump_token.position,
+                label,
+            }),
+        }
+    }
+
+    /// Parse a statement of a block that starts with a keyword or a label, inside it's None when
+    /// the item starts with an expression -> var x = 1; return x; while ... { }
+    fn parse_block_item(&mut self) -> Option<Option<parser::statements::Statement>> {
+        let statement: Option<parser::statements::Statement> = match self.current_kind() {
+            lexer::tokens::TokenKind::KwVar => {
+                self.parse_var_statement(parser::statements::Visibility::Private)
+            }
+            lexer::tokens::TokenKind::KwConst => {
+                self.parse_const_statement(parser::statements::Visibility::Private)
+            }
+            lexer::tokens::TokenKind::KwReturn => self.parse_return_statement(),
+            lexer::tokens::TokenKind::KwThrow => self.parse_throw_statement(),
+            lexer::tokens::TokenKind::KwTry => self.parse_try_statement(),
+            lexer::tokens::TokenKind::KwDefer => self.parse_defer_statement(),
+            lexer::tokens::TokenKind::KwWhile => self.parse_while_statement(),
+            lexer::tokens::TokenKind::KwLoop => self.parse_loop_statement(),
+            lexer::tokens::TokenKind::KwFor => self.parse_for_statement(),
+            lexer::tokens::TokenKind::Label => self.parse_labeled_statement(),
+            lexer::tokens::TokenKind::KwBreak | lexer::tokens::TokenKind::KwContinue => {
+                self.parse_jump_statement()
+            }
+            _ => {
+                return Some(None);
+            }
+        };
+
+        statement.map(Some)
     }
 
     /// Parse a block statement -> { ... statements ... }
-    fn parse_block_statement(&mut self) -> Option<Box<Vec<parser::statements::Statement>>> {
+    fn parse_block_statement(&mut self) -> Option<Vec<parser::statements::Statement>> {
         // '{'
         if let Some(message) = handling::Message::expected_or_error(
             lexer::tokens::TokenKind::LeftBrace,
@@ -704,7 +2232,7 @@ impl Parser {
         }
         self.advance();
 
-        let mut block: Box<Vec<parser::statements::Statement>> = Box::new(Vec::new());
+        let mut block: Vec<parser::statements::Statement> = Vec::new();
 
         while !(self
             .current_kind()
@@ -712,17 +2240,19 @@ impl Parser {
             || self.current_kind().eq(&lexer::tokens::TokenKind::Eof))
         {
             let statement: Option<parser::statements::Statement> = match self.current_kind() {
-                lexer::tokens::TokenKind::KwVar => self.parse_var_statement(),
-                lexer::tokens::TokenKind::KwConst => self.parse_const_statement(),
                 lexer::tokens::TokenKind::Identifier => self.parse_identifier_statement(),
-                lexer::tokens::TokenKind::KwReturn => self.parse_return_statement(),
-                _ => {
-                    self.output.push(handling::Message::expected_error(
-                        "a statement",
-                        self.current(),
-                    ));
-                    return None;
-                }
+                lexer::tokens::TokenKind::KwIf => self.parse_if_statement(),
+                _ => match self.parse_block_item() {
+                    Some(Some(statement)) => Some(statement),
+                    Some(None) => {
+                        self.output.push(handling::Message::expected_error(
+                            "a statement",
+                            self.current(),
+                        ));
+                        return None;
+                    }
+                    None => None,
+                },
             };
 
             if let Some(statement) = statement {
@@ -745,7 +2275,7 @@ impl Parser {
             }
         }
 
-        return Some(block);
+        Some(block)
     }
 
     /// Parse the parameters inside parenthesis -> (param: type, other_param: type)
@@ -803,7 +2333,61 @@ impl Parser {
             };
             self.advance();
 
-            param = parser::statements::FuncParam { name, r#type };
+            // The remaining arguments are given as an array -> values: int...
+            let variadic: bool = self.peek_expect(&lexer::tokens::TokenKind::Ellipsis);
+            let r#type: internals::types::Types = if variadic {
+                self.advance();
+                internals::types::Types::Slice(Box::new(r#type))
+            } else {
+                r#type
+            };
+
+            // The default value of the parameter -> count: int = 1
+            let default: Option<parser::statements::Expression> = if self
+                .peek_expect(&lexer::tokens::TokenKind::OpAssign)
+            {
+                if variadic {
+                    self.output.push(handling::Message::new(
+                        handling::MessageKind::CodeError,
+                        format!(
+                            "\"{}\" -> A variadic parameter can't have a default value",
+                            name
+                        ),
+                        Some(self.current().position.clone()),
+                    ));
+                    return None;
+                }
+
+                self.advance();
+
+                let default: parser::statements::Expression = match self.parse_nested_expression() {
+                    Some(expr) => expr,
+                    None => {
+                        return None;
+                    }
+                };
+                self.advance();
+
+                Some(default)
+            } else {
+                None
+            };
+
+            if variadic && self.peek_expect(&lexer::tokens::TokenKind::Comma) {
+                self.output.push(handling::Message::new(
+                    handling::MessageKind::CodeError,
+                    format!("\"{}\" -> A variadic parameter must be the last one", name),
+                    Some(self.current().position.clone()),
+                ));
+                return None;
+            }
+
+            param = parser::statements::FuncParam {
+                name,
+                r#type,
+                default,
+                variadic,
+            };
 
             // The end of the parameters or another parameter
             if self.peek_expect(&lexer::tokens::TokenKind::RightParen) {
@@ -827,16 +2411,32 @@ impl Parser {
             return None;
         }
 
-        return Some(params);
+        Some(params)
     }
 
     /// Parse a function statement -> func identifier (parameters) -> return_type { ... statements ... }
-    fn parse_function_statement(&mut self) -> Option<parser::statements::Statement> {
+    fn parse_function_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
         let func_token: lexer::tokens::Token = self.current().clone();
         self.advance();
 
         let name = match self.current().kind {
-            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            lexer::tokens::TokenKind::Identifier | lexer::tokens::TokenKind::KwNew
+                if func_token.kind == lexer::tokens::TokenKind::KwFunc =>
+            {
+                self.current().value.clone()
+            }
+            // The operators of a type are methods named after them -> over +(other: Self)
+            _ if func_token.kind == lexer::tokens::TokenKind::KwOver => {
+                match self.parse_overloaded_operator() {
+                    Some(name) => name,
+                    None => {
+                        return None;
+                    }
+                }
+            }
             _ => {
                 self.output.push(handling::Message::expected_error(
                     "identifier",
@@ -845,7 +2445,21 @@ impl Parser {
                 return None;
             }
         };
-        self.advance();
+        self.advance();
+
+        // The type parameters of a generic function -> func max<T>(...)
+        let generics: Vec<parser::statements::GenericParam> = match self.current_kind() {
+            lexer::tokens::TokenKind::OpLt => match self.parse_generic_params() {
+                Some(generics) => {
+                    self.advance();
+                    generics
+                }
+                None => {
+                    return None;
+                }
+            },
+            _ => Vec::new(),
+        };
 
         let params: Vec<parser::statements::FuncParam> =
             match self.parse_function_parameters_statement() {
@@ -866,7 +2480,7 @@ impl Parser {
         }
         self.advance();
 
-        let r#type: internals::types::Types = match self.get_type() {
+        let r#type: internals::types::Types = match self.parse_return_type() {
             Some(t) => t,
             None => {
                 return None;
@@ -877,8 +2491,10 @@ impl Parser {
         match self.current().kind {
             lexer::tokens::TokenKind::Semicolon => {
                 return Some(parser::statements::Statement::FunctionDeclaration {
+                    visibility,
                     start: func_token.position,
                     name,
+                    generics,
                     params: if params.is_empty() {
                         None
                     } else {
@@ -899,15 +2515,17 @@ impl Parser {
         }
 
         let body: Box<Vec<parser::statements::Statement>> = match self.parse_block_statement() {
-            Some(body) => body,
+            Some(body) => Box::new(body),
             None => {
                 return None;
             }
         };
 
         Some(parser::statements::Statement::FunctionDeclaration {
+            visibility,
             start: func_token.position,
             name,
+            generics,
             params: if params.is_empty() {
                 None
             } else {
@@ -918,36 +2536,454 @@ impl Parser {
         })
     }
 
-    fn parse_identifier_statement(&mut self) -> Option<parser::statements::Statement> {
-        match self.current_kind() {
-            lexer::tokens::TokenKind::Identifier => match self.peek_kind() {
-                lexer::tokens::TokenKind::LeftParen => {
-                    let func_call: parser::statements::Expression = match self.parse_function_call()
-                    {
-                        Some(func_call) => func_call,
-                        None => return None,
+    /// Parses the operator of an overload, "!=" comes from "==" -> '+', "==", "[]"
+    fn parse_overloaded_operator(&mut self) -> Option<String> {
+        if self.peek_expect(&lexer::tokens::TokenKind::LeftBracket) {
+            self.advance();
+
+            if let Some(message) = handling::Message::expected_or_error(
+                lexer::tokens::TokenKind::RightBracket,
+                "']'",
+                self.current(),
+            ) {
+                self.output.push(message);
+                return None;
+            }
+
+            return Some("[]".to_string());
+        }
+
+        match self.current_kind().overload() {
+            Some(name) if name == self.current().value => Some(name.to_string()),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "an operator that can be overloaded",
+                    self.current(),
+                ));
+                None
+            }
+        }
+    }
+
+    /// Parses another name for a type -> type Meters = double; or type UserId = new int;
+    fn parse_type_alias_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
+        // "type" <- Token
+        let type_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let name: String = match self.current_kind() {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "a type name",
+                    self.current(),
+                ));
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::OpAssign,
+            "\'=\'",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        // "new" <- A distinct type, not just another name
+        let distinct: bool = self.peek_expect(&lexer::tokens::TokenKind::KwNew);
+        if distinct {
+            self.advance();
+        }
+
+        let r#type: internals::types::Types = match self.get_type() {
+            Some(r#type) => r#type,
+            None => {
+                return None;
+            }
+        };
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::TypeAlias {
+            visibility,
+            start: type_token.position,
+            name,
+            r#type,
+            distinct,
+        })
+    }
+
+    /// Parse a struct or class declaration -> struct Name<T> { field: type; func method() -> type { ... } }
+    fn parse_type_statement(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
+        // "struct" or "class" <- Token
+        let type_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        let kind: parser::statements::TypeKind = match type_token.kind {
+            lexer::tokens::TokenKind::KwClass => parser::statements::TypeKind::Class,
+            _ => parser::statements::TypeKind::Struct,
+        };
+
+        let name: String = match self.current_kind() {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
+            _ => {
+                self.output.push(handling::Message::expected_error(
+                    "a type name",
+                    self.current(),
+                ));
+                return None;
+            }
+        };
+        self.advance();
+
+        let generics: Vec<parser::statements::GenericParam> = match self.current_kind() {
+            lexer::tokens::TokenKind::OpLt => match self.parse_generic_params() {
+                Some(generics) => {
+                    self.advance();
+                    generics
+                }
+                None => {
+                    return None;
+                }
+            },
+            _ => Vec::new(),
+        };
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::LeftBrace,
+            "start of block",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+        self.advance();
+
+        let mut fields: Vec<parser::statements::Field> = Vec::new();
+        let mut methods: Vec<parser::statements::Statement> = Vec::new();
+        let mut statics: Vec<parser::statements::Statement> = Vec::new();
+
+        while !(self.peek_expect(&lexer::tokens::TokenKind::RightBrace)
+            || self.peek_expect(&lexer::tokens::TokenKind::Eof))
+        {
+            let member_visibility: parser::statements::Visibility =
+                self.parse_visibility().unwrap_or_default();
+
+            match self.current_kind() {
+                lexer::tokens::TokenKind::KwStatic => {
+                    match self.parse_static_member(member_visibility) {
+                        Some(member) => statics.push(Self::qualify_static(&name, member)),
+                        None => {
+                            return None;
+                        }
+                    }
+                }
+                // An associated constant -> const MAX: int = 10;
+                lexer::tokens::TokenKind::KwConst => {
+                    match self.parse_const_statement(member_visibility) {
+                        Some(constant) => statics.push(Self::qualify_static(&name, constant)),
+                        None => {
+                            return None;
+                        }
+                    }
+                }
+                lexer::tokens::TokenKind::KwFunc | lexer::tokens::TokenKind::KwOver => {
+                    match self.parse_function_statement(member_visibility) {
+                        Some(method) => methods.push(method),
+                        None => {
+                            return None;
+                        }
+                    }
+                }
+                // "name: type;" <- A field
+                lexer::tokens::TokenKind::Identifier => {
+                    let field_name: String = self.current().value.clone();
+                    self.advance();
+
+                    if let Some(message) = handling::Message::expected_or_error(
+                        lexer::tokens::TokenKind::Colon,
+                        "\':\'",
+                        self.current(),
+                    ) {
+                        self.output.push(message);
+                        return None;
+                    }
+                    self.advance();
+
+                    let r#type: internals::types::Types = match self.get_type() {
+                        Some(t) => t,
+                        None => {
+                            return None;
+                        }
                     };
                     self.advance();
 
-                    if self.current_kind().to_owned() != lexer::tokens::TokenKind::Semicolon {
-                        self.output.push(handling::Message::expected_error(
-                            "end of statement",
-                            self.current(),
-                        ));
+                    if let Some(message) = handling::Message::expected_or_error(
+                        lexer::tokens::TokenKind::Semicolon,
+                        "end of statement",
+                        self.current(),
+                    ) {
+                        self.output.push(message);
                         return None;
                     }
 
-                    return Some(parser::statements::Statement::FunctionCall(func_call));
+                    fields.push(parser::statements::Field {
+                        visibility: member_visibility,
+                        name: field_name,
+                        r#type,
+                    });
                 }
-                _ => return None,
-            },
+                _ => {
+                    self.output.push(handling::Message::expected_error(
+                        "a field or a method",
+                        self.current(),
+                    ));
+                    return None;
+                }
+            }
+
+            self.advance();
+        }
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::RightBrace,
+            "end of block",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::TypeDeclaration {
+            visibility,
+            start: type_token.position,
+            kind,
+            name,
+            generics,
+            fields,
+            methods,
+            statics,
+        })
+    }
+
+    /// Parses a member that belongs to its type instead of its instances -> static count: int = 0;
+    /// or static func create() -> Self { ... }
+    fn parse_static_member(
+        &mut self,
+        visibility: parser::statements::Visibility,
+    ) -> Option<parser::statements::Statement> {
+        // "static" <- Token
+        let static_token: lexer::tokens::Token = self.current().clone();
+        self.advance();
+
+        if self.peek_expect(&lexer::tokens::TokenKind::KwFunc) {
+            return self.parse_function_statement(visibility);
+        }
+
+        let name: String = match self.current_kind() {
+            lexer::tokens::TokenKind::Identifier => self.current().value.clone(),
             _ => {
                 self.output.push(handling::Message::expected_error(
-                    "an identifier",
+                    "a field or a method",
                     self.current(),
                 ));
                 return None;
             }
         };
+        self.advance();
+
+        let r#type: internals::types::Types = match self.parse_type_annotation() {
+            Some(r#type) => r#type,
+            None => {
+                return None;
+            }
+        };
+
+        let value: Option<parser::statements::Expression> = match self.current_kind() {
+            lexer::tokens::TokenKind::OpAssign => {
+                self.advance();
+
+                let value: parser::statements::Expression = match self.parse_expression() {
+                    Some(value) => value,
+                    None => {
+                        return None;
+                    }
+                };
+                self.advance();
+
+                Some(value)
+            }
+            _ => None,
+        };
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(parser::statements::Statement::VariableDeclaration {
+            visibility,
+            start: static_token.position,
+            name,
+            r#type,
+            value,
+        })
+    }
+
+    /// Names a static member after its type, it's reached through it -> Point::origin
+    fn qualify_static(
+        type_name: &str,
+        mut member: parser::statements::Statement,
+    ) -> parser::statements::Statement {
+        if let parser::statements::Statement::VariableDeclaration { name, .. }
+        | parser::statements::Statement::ConstantDeclaration { name, .. }
+        | parser::statements::Statement::FunctionDeclaration { name, .. } = &mut member
+        {
+            *name = format!("{}::{}", type_name, name);
+        }
+
+        member
+    }
+
+    /// Parse a statement that starts with an identifier, without its terminator -> function_identifier(arguments), target = value, target++
+    fn parse_simple_statement(&mut self) -> Option<parser::statements::Statement> {
+        let start_token: lexer::tokens::Token = self.current().clone();
+
+        if start_token.kind != lexer::tokens::TokenKind::Identifier {
+            self.output.push(handling::Message::expected_error(
+                "an identifier",
+                self.current(),
+            ));
+            return None;
+        }
+
+        let target: parser::statements::Expression = match self.parse_expression() {
+            Some(expr) => expr,
+            None => {
+                return None;
+            }
+        };
+
+        self.parse_simple_statement_rest(start_token, target)
+    }
+
+    /// Parse what follows the target of a simple statement -> an assignment or the end of a call
+    fn parse_simple_statement_rest(
+        &mut self,
+        start_token: lexer::tokens::Token,
+        target: parser::statements::Expression,
+    ) -> Option<parser::statements::Statement> {
+        match self.peek_kind().clone() {
+            lexer::tokens::TokenKind::OpAssign
+            | lexer::tokens::TokenKind::OpAddAssign
+            | lexer::tokens::TokenKind::OpSubAssign
+            | lexer::tokens::TokenKind::OpMulAssign
+            | lexer::tokens::TokenKind::OpDivAssign
+            | lexer::tokens::TokenKind::OpModAssign
+            | lexer::tokens::TokenKind::OpBitAndAssign
+            | lexer::tokens::TokenKind::OpBitOrAssign
+            | lexer::tokens::TokenKind::OpBitXorAssign
+            | lexer::tokens::TokenKind::OpShlAssign
+            | lexer::tokens::TokenKind::OpShrAssign
+            | lexer::tokens::TokenKind::OpInc
+            | lexer::tokens::TokenKind::OpDec => {
+                if !target.is_assignable() {
+                    self.output.push(handling::Message::new(
+                        handling::MessageKind::CodeError,
+                        "Invalid assignment target".to_string(),
+                        Some(start_token.position),
+                    ));
+                    return None;
+                }
+
+                self.advance();
+                let operator: lexer::tokens::TokenKind = self.current_kind().clone();
+
+                // "++" and "--" don't have a value
+                let value: Option<parser::statements::Expression> = match operator {
+                    lexer::tokens::TokenKind::OpInc | lexer::tokens::TokenKind::OpDec => None,
+                    _ => {
+                        self.advance();
+
+                        match self.parse_expression() {
+                            Some(expr) => Some(expr),
+                            None => {
+                                return None;
+                            }
+                        }
+                    }
+                };
+
+                Some(parser::statements::Statement::VariableAlteration {
+                    start: start_token.position,
+                    target,
+                    operator,
+                    value,
+                })
+            }
+            _ => match target {
+                parser::statements::Expression::Call { .. } => {
+                    Some(parser::statements::Statement::FunctionCall {
+                        start: start_token.position,
+                        call: target,
+                    })
+                }
+                // A call whose error is propagated -> save(data)?;
+                parser::statements::Expression::Propagate { ref value, .. }
+                    if matches!(value.as_ref(), parser::statements::Expression::Call { .. }) =>
+                {
+                    Some(parser::statements::Statement::FunctionCall {
+                        start: start_token.position,
+                        call: target,
+                    })
+                }
+                _ => {
+                    self.advance();
+                    self.output.push(handling::Message::expected_error(
+                        "an assignment or a function call",
+                        self.current(),
+                    ));
+                    None
+                }
+            },
+        }
+    }
+
+    fn parse_identifier_statement(&mut self) -> Option<parser::statements::Statement> {
+        let statement: parser::statements::Statement = self.parse_simple_statement()?;
+        self.advance();
+
+        if let Some(message) = handling::Message::expected_or_error(
+            lexer::tokens::TokenKind::Semicolon,
+            "end of statement",
+            self.current(),
+        ) {
+            self.output.push(message);
+            return None;
+        }
+
+        Some(statement)
     }
 }
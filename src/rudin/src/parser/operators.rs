@@ -0,0 +1,112 @@
+use crate::*;
+
+use lexer::tokens::TokenKind;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// The expression node an infix operator produces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InfixNode {
+    Binary,
+    Logical,
+    Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fixity {
+    /// operator operand -> -x, !x
+    Prefix,
+    /// left operator right -> a + b
+    Infix(Associativity, InfixNode),
+    /// operand operator -> f(x), f(x)?, a[i], a.b, a?.b, A::b, x as int
+    Postfix,
+}
+
+#[derive(Debug, Clone)]
+pub struct Operator {
+    pub token: TokenKind,
+    pub fixity: Fixity,
+    /// How tightly the operator binds, higher binds tighter
+    pub precedence: u8,
+}
+
+const fn operator(token: TokenKind, fixity: Fixity, precedence: u8) -> Operator {
+    Operator {
+        token,
+        fixity,
+        precedence,
+    }
+}
+
+const LEFT_BINARY: Fixity = Fixity::Infix(Associativity::Left, InfixNode::Binary);
+const LEFT_LOGICAL: Fixity = Fixity::Infix(Associativity::Left, InfixNode::Logical);
+
+const LEFT_RANGE: Fixity = Fixity::Infix(Associativity::Left, InfixNode::Range);
+
+/// Every operator of the expressions, a new operator only needs an entry here.
+pub const OPERATORS: &[Operator] = &[
+    // Ranges -> 0..n + 1 is 0..(n + 1)
+    operator(TokenKind::DotDot, LEFT_RANGE, 1),
+    operator(TokenKind::DotDotEq, LEFT_RANGE, 1),
+    // Null coalescing -> a ?? b ?? c is a ?? (b ?? c)
+    operator(
+        TokenKind::OpCoalesce,
+        Fixity::Infix(Associativity::Right, InfixNode::Logical),
+        2,
+    ),
+    // Logical
+    operator(TokenKind::OpOr, LEFT_LOGICAL, 3),
+    operator(TokenKind::OpAnd, LEFT_LOGICAL, 4),
+    // Comparison
+    operator(TokenKind::OpEq, LEFT_LOGICAL, 5),
+    operator(TokenKind::OpNeq, LEFT_LOGICAL, 5),
+    operator(TokenKind::OpLt, LEFT_LOGICAL, 6),
+    operator(TokenKind::OpLe, LEFT_LOGICAL, 6),
+    operator(TokenKind::OpGt, LEFT_LOGICAL, 6),
+    operator(TokenKind::OpGe, LEFT_LOGICAL, 6),
+    // Bitwise -> a & mask == 0 is (a & mask) == 0
+    operator(TokenKind::OpBitOr, LEFT_BINARY, 7),
+    operator(TokenKind::OpBitXor, LEFT_BINARY, 8),
+    operator(TokenKind::OpBitAnd, LEFT_BINARY, 9),
+    // Shifts -> 1 << n + 1 is 1 << (n + 1)
+    operator(TokenKind::OpShl, LEFT_BINARY, 10),
+    operator(TokenKind::OpShr, LEFT_BINARY, 10),
+    // Arithmetic
+    operator(TokenKind::OpAdd, LEFT_BINARY, 11),
+    operator(TokenKind::OpSub, LEFT_BINARY, 11),
+    operator(TokenKind::OpMul, LEFT_BINARY, 12),
+    operator(TokenKind::OpDiv, LEFT_BINARY, 12),
+    operator(TokenKind::OpMod, LEFT_BINARY, 12),
+    // Casts -> -x as int is (-x) as int, a * b as int is a * (b as int)
+    operator(TokenKind::KwAs, Fixity::Postfix, 13),
+    // Unary
+    operator(TokenKind::OpSub, Fixity::Prefix, 14),
+    operator(TokenKind::OpAdd, Fixity::Prefix, 14),
+    operator(TokenKind::OpNot, Fixity::Prefix, 14),
+    operator(TokenKind::OpBitNot, Fixity::Prefix, 14),
+    // Calls, indexes and members
+    operator(TokenKind::LeftParen, Fixity::Postfix, 15),
+    operator(TokenKind::Question, Fixity::Postfix, 15),
+    operator(TokenKind::LeftBracket, Fixity::Postfix, 15),
+    operator(TokenKind::Dot, Fixity::Postfix, 15),
+    operator(TokenKind::QuestionDot, Fixity::Postfix, 15),
+    operator(TokenKind::ColonColon, Fixity::Postfix, 15),
+];
+
+/// Finds the prefix operator of a token.
+pub fn prefix(token: &TokenKind) -> Option<&'static Operator> {
+    OPERATORS
+        .iter()
+        .find(|operator| operator.token == *token && operator.fixity == Fixity::Prefix)
+}
+
+/// Finds the operator of a token that follows an operand -> infix or postfix
+pub fn infix(token: &TokenKind) -> Option<&'static Operator> {
+    OPERATORS
+        .iter()
+        .find(|operator| operator.token == *token && operator.fixity != Fixity::Prefix)
+}
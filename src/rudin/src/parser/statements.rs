@@ -7,24 +7,57 @@ pub enum Statement {
         body: Box<Vec<Statement>>,
     },
     VariableDeclaration {
+        visibility: Visibility,
         start: internals::Position,
         name: String,
         r#type: internals::types::Types,
         value: Option<Expression>,
     },
+    /// Declares a variable for each element of a tuple -> var (a, b) = pair();
+    Destructure {
+        visibility: Visibility,
+        start: internals::Position,
+        names: Vec<String>,
+        r#type: internals::types::Types,
+        value: Expression,
+    },
     ConstantDeclaration {
+        visibility: Visibility,
         start: internals::Position,
         name: String,
         r#type: internals::types::Types,
         value: Expression,
     },
     FunctionDeclaration {
+        visibility: Visibility,
         start: internals::Position,
         name: String,
+        generics: Vec<GenericParam>,
         r#type: internals::types::Types,
         params: Option<Vec<FuncParam>>,
         body: Option<Box<Vec<Statement>>>,
     },
+    TypeDeclaration {
+        visibility: Visibility,
+        start: internals::Position,
+        kind: TypeKind,
+        name: String,
+        generics: Vec<GenericParam>,
+        fields: Vec<Field>,
+        methods: Vec<Statement>,
+        /// The static fields, associated constants and static methods, named after the type
+        /// -> Point::origin
+        statics: Vec<Statement>,
+    },
+    /// Another name for a type -> type Meters = double;
+    /// A distinct one only converts from and to it with a cast -> type UserId = new int;
+    TypeAlias {
+        visibility: Visibility,
+        start: internals::Position,
+        name: String,
+        r#type: internals::types::Types,
+        distinct: bool,
+    },
     If {
         start: internals::Position,
         condition: Expression,
@@ -43,33 +76,91 @@ pub enum Statement {
     },
     While {
         start: internals::Position,
+        label: Option<String>,
         condition: Expression,
         body: Option<Box<Vec<Statement>>>,
     },
     For {
         start: internals::Position,
+        label: Option<String>,
         variable: Option<Box<Statement>>,
         condition: Option<Expression>,
         variable_update: Option<Box<Statement>>,
         body: Option<Box<Vec<Statement>>>,
-        alternate: Option<Box<Vec<Statement>>>,
     },
+    /// Runs the body once for each item of an array, a range, a str or an iterator -> for item in items { ... }
+    ForIn {
+        start: internals::Position,
+        label: Option<String>,
+        name: String,
+        iterable: Expression,
+        body: Option<Box<Vec<Statement>>>,
+    },
+    Loop {
+        start: internals::Position,
+        label: Option<String>,
+        body: Option<Box<Vec<Statement>>>,
+    },
+    /// Leaves the innermost loop or the one with the label, a loop used as a value takes the
+    /// value -> break 'outer; break count;
     Break {
         start: internals::Position,
+        label: Option<String>,
+        value: Option<Expression>,
     },
     Continue {
         start: internals::Position,
+        label: Option<String>,
     },
     Return {
         start: internals::Position,
         expression: Option<Expression>,
     },
     VariableAlteration {
-        name: String,
+        start: internals::Position,
+        target: Expression,
         operator: lexer::tokens::TokenKind,
+        value: Option<Expression>,
+    },
+    Use {
+        start: internals::Position,
+        module: String,
+    },
+    /// Leaves the function with an error -> throw "message";
+    Throw {
+        start: internals::Position,
         value: Expression,
     },
-    FunctionCall(Expression),
+    /// Runs the catch block when the body throws, the error is bound to the name if any
+    Try {
+        start: internals::Position,
+        body: Option<Box<Vec<Statement>>>,
+        name: Option<String>,
+        catch: Option<Box<Vec<Statement>>>,
+    },
+    /// Runs its body when the block around it is left, the last deferred body runs first
+    Defer {
+        start: internals::Position,
+        body: Option<Box<Vec<Statement>>>,
+    },
+    /// An expression run only for its effects -> an if whose value isn't used
+    Expression {
+        start: internals::Position,
+        expression: Expression,
+    },
+    FunctionCall {
+        start: internals::Position,
+        call: Expression,
+    },
+}
+
+/// Who can access a declaration from outside of its module.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Visibility {
+    Public,
+    #[default]
+    Private,
+    Protected,
 }
 
 #[derive(Debug, Clone)]
@@ -78,16 +169,41 @@ pub enum Loop {
     No,
 }
 
+/// The keyword a user defined type was declared with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    Struct,
+    Class,
+}
+
+/// A type parameter and the traits it must implement -> T: Comparable + Printable
+#[derive(Debug, Clone)]
+pub struct GenericParam {
+    pub name: String,
+    pub bounds: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub visibility: Visibility,
+    pub name: String,
+    pub r#type: internals::types::Types,
+}
+
 #[derive(Debug, Clone)]
 pub struct FuncParam {
     pub name: String,
     pub r#type: internals::types::Types,
+    // The value used when a call doesn't give the parameter -> count: int = 1
+    pub default: Option<Expression>,
+    // Takes the remaining arguments of a call as an array -> values: int...
+    pub variadic: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum ArrayAcess {
     Acess {
-        name: String,
+        array: Box<Expression>,
         index: Box<Expression>,
     },
     NestedAcess {
@@ -96,6 +212,16 @@ pub enum ArrayAcess {
     },
 }
 
+impl ArrayAcess {
+    /// Returns the variable at the root of the indexed array -> matrix in matrix[i][j]
+    pub fn root_identifier(&self) -> Option<&String> {
+        match self {
+            ArrayAcess::Acess { array, .. } => array.root_identifier(),
+            ArrayAcess::NestedAcess { acess, .. } => acess.root_identifier(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Expression {
     Identifier(String),
@@ -120,11 +246,139 @@ pub enum Expression {
     ArrayLiteral {
         elements: Option<Box<Vec<Expression>>>,
     },
+    /// The integers from one bound to the other, the last one only when inclusive -> 0..10, 1..=n
+    Range {
+        from: Box<Expression>,
+        to: Box<Expression>,
+        inclusive: bool,
+    },
+    /// Values of any type grouped together, its elements are read by position -> (1, "a")
+    Tuple {
+        elements: Vec<Expression>,
+    },
     ArrayAcess(ArrayAcess),
+    Member {
+        object: Box<Expression>,
+        member: String,
+    },
+    /// A member that is null when the object is null -> object?.member
+    OptionalMember {
+        object: Box<Expression>,
+        member: String,
+    },
+    StaticMember {
+        r#type: Box<Expression>,
+        member: String,
+    },
     Call {
-        name: String,
+        callee: Box<Expression>,
         arguments: Option<Box<Vec<Expression>>>,
+        /// Where each argument starts, the diagnostics of an argument point at it
+        starts: Vec<internals::Position>,
+    },
+    StructLiteral {
+        name: String,
+        fields: Vec<(String, Expression)>,
+    },
+    /// A block whose value is its trailing expression, the type is known after the analysis
+    Block {
+        body: Box<Vec<Statement>>,
+        value: Option<Box<Expression>>,
+        r#type: internals::types::Types,
+    },
+    /// A loop used as a value, the value of its breaks -> loop { break 1; }
+    Loop {
+        label: Option<String>,
+        body: Box<Vec<Statement>>,
+        r#type: internals::types::Types,
+    },
+    /// An if used as a value, the alternate is another if or the else block
+    If {
+        start: internals::Position,
+        condition: Box<Expression>,
+        body: Box<Expression>,
+        alternate: Option<Box<Expression>>,
+    },
+    /// An array given as the arguments of a variadic parameter -> sum(...values)
+    Spread {
+        start: internals::Position,
+        value: Box<Expression>,
+    },
+    /// An argument given by the name of its parameter -> f(count: 2)
+    NamedArgument {
+        start: internals::Position,
+        name: String,
+        value: Box<Expression>,
+    },
+    /// Gives the error of a call to the function around it or to the catch block -> parse(s)?
+    Propagate {
+        start: internals::Position,
+        value: Box<Expression>,
     },
+    /// A conversion to another type -> value as int
+    Cast {
+        value: Box<Expression>,
+        r#type: internals::types::Types,
+    },
+    /// An anonymous function, its arrow form has a single return statement as body
+    Lambda {
+        start: internals::Position,
+        params: Vec<FuncParam>,
+        r#type: internals::types::Types,
+        body: Box<Vec<Statement>>,
+    },
+}
+
+impl Expression {
+    /// Checks if the expression can be on the left side of an assignment.
+    pub fn is_assignable(&self) -> bool {
+        matches!(
+            self,
+            Expression::Identifier(_)
+                | Expression::Member { .. }
+                | Expression::StaticMember { .. }
+                | Expression::ArrayAcess(_)
+        )
+    }
+
+    /// Returns the qualified name of a chain of identifiers -> std.console.printl, Type::member
+    pub fn path_name(&self) -> Option<String> {
+        match self {
+            Expression::Identifier(name) => Some(name.clone()),
+            Expression::Member { object, member } => {
+                Some(format!("{}.{}", object.path_name()?, member))
+            }
+            Expression::StaticMember { r#type, member } => {
+                Some(format!("{}::{}", r#type.path_name()?, member))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if the expression is made only of number literals -> 3.14 * 2
+    pub fn is_number_literal(&self) -> bool {
+        match self {
+            Expression::Literal {
+                r#type: lexer::tokens::TokenKind::Number,
+                ..
+            } => true,
+            Expression::Unary { operand, .. } => operand.is_number_literal(),
+            Expression::Binary { left, right, .. } => {
+                left.is_number_literal() && right.is_number_literal()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the variable at the root of a chain of members -> player in player.position.x
+    pub fn root_identifier(&self) -> Option<&String> {
+        match self {
+            Expression::Identifier(name) => Some(name),
+            Expression::Member { object, .. } => object.root_identifier(),
+            Expression::ArrayAcess(acess) => acess.root_identifier(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,86 @@
+//! Checks the types the analyzer writes back on the declarations without annotation.
+
+/// Analyzes a program and returns its analyzed AST, as printed by Debug.
+fn analyze(source: &str) -> String {
+    let mut lexer: rudin::lexer::Lexer = rudin::lexer::Lexer::new(source.to_string());
+    lexer.lex();
+    assert!(lexer.output.is_empty(), "{:?}", lexer.output);
+
+    let mut parser: rudin::parser::Parser = rudin::parser::Parser::new(lexer.tokens);
+    parser.parse();
+    assert!(parser.output.is_empty(), "{:?}", parser.output);
+
+    let mut analyzer: rudin::analyzer::Analyzer = rudin::analyzer::Analyzer::new(parser.ast);
+    analyzer.analyze();
+    assert!(analyzer.output.is_empty(), "{:?}", analyzer.output);
+
+    format!("{:?}", analyzer.ast)
+}
+
+/// Checks that a declaration of the analyzed AST has the expected type.
+fn expect_annotation(ast: &str, name: &str, r#type: &str) {
+    let declaration: String = format!("name: {:?}, type: {}", name, r#type);
+
+    assert!(
+        ast.contains(&declaration),
+        "{} should be annotated as {}:\n{}",
+        name,
+        r#type,
+        ast
+    );
+}
+
+#[test]
+fn methods_and_expression_bodies() {
+    let ast: String = analyze(
+        r#"struct Counter {
+    pub count: int;
+
+    pub func bump() -> int {
+        var next = self.count + 1;
+        return next;
+    }
+}
+
+func main() -> int {
+    var twice = func(x: int) -> int {
+        var doubled = x * 2;
+        return doubled;
+    };
+
+    var half = {
+        var ratio = 0.5;
+        ratio * 3
+    };
+
+    if half > 1 {
+        var flag = 'c';
+    }
+
+    return twice(1);
+}"#,
+    );
+
+    expect_annotation(&ast, "next", "Int");
+    expect_annotation(&ast, "doubled", "Int");
+    expect_annotation(&ast, "ratio", "Double");
+    expect_annotation(&ast, "flag", "Char");
+}
+
+#[test]
+fn generic_instances() {
+    let ast: String = analyze(
+        r#"func keep<T>(value: T) -> T {
+    var copy = value;
+    return copy;
+}
+
+func main() -> int {
+    println(keep("a"));
+    return keep(1);
+}"#,
+    );
+
+    expect_annotation(&ast, "copy", "String");
+    expect_annotation(&ast, "copy", "Int");
+}